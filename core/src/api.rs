@@ -7,12 +7,17 @@ use actix_web::{
 
 use tracing_actix_web::TracingLogger;
 
+pub mod error;
 pub mod lexicon;
+pub mod openapi;
+pub mod review;
 pub mod verse;
 
 use crate::{
     api::{
+        error::ApiError,
         lexicon::{lexicon_controller, lexicon_repo},
+        review::{review_controller, review_repo},
         verse::{verse_controller, verse_repo},
     },
     config::EnvVar,
@@ -36,16 +41,24 @@ pub async fn init() -> Result<(), SafeError> {
 
     verse_repo::configure().await?;
     lexicon_repo::configure().await?;
+    review_repo::configure().await?;
 
     HttpServer::new(|| {
         let routes = web::scope("v1")
             .configure(verse_controller::configure)
-            .configure(lexicon_controller::configure);
+            .configure(lexicon_controller::configure)
+            .configure(review_controller::configure)
+            .configure(openapi::configure);
+
+        let path_config = web::PathConfig::default()
+            .error_handler(|e, _| ApiError::bad_request(e.to_string()).into());
 
         App::new()
+            .app_data(path_config)
             .wrap(TracingLogger::default())
             .wrap(cors())
             .service(routes)
+            .service(openapi::get_docs)
     })
     .bind(("127.0.0.1", port))?
     .run()
@@ -0,0 +1,130 @@
+use actix_web::{http::StatusCode, HttpResponse, ResponseError};
+use serde::Serialize;
+use thiserror::Error;
+use tracing::error;
+use utoipa::ToSchema;
+
+use crate::error::SafeError;
+
+#[derive(Error, Debug)]
+pub enum ApiError {
+    #[error("{detail}")]
+    NotFound {
+        detail: String,
+        param: Option<String>,
+    },
+    #[error("{detail}")]
+    BadRequest {
+        detail: String,
+        param: Option<String>,
+    },
+    #[error("{detail}")]
+    Unprocessable {
+        detail: String,
+        param: Option<String>,
+    },
+    #[error("{detail}")]
+    Conflict { detail: String },
+    #[error(transparent)]
+    Internal(#[from] SafeError),
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Debug, ToSchema)]
+pub struct Problem {
+    #[serde(rename = "type")]
+    pub kind: &'static str,
+    pub title: &'static str,
+    pub status: u16,
+    pub detail: String,
+    pub param: Option<String>,
+}
+
+impl ApiError {
+    pub fn not_found(detail: impl Into<String>) -> Self {
+        ApiError::NotFound {
+            detail: detail.into(),
+            param: None,
+        }
+    }
+
+    pub fn bad_request(detail: impl Into<String>) -> Self {
+        ApiError::BadRequest {
+            detail: detail.into(),
+            param: None,
+        }
+    }
+
+    pub fn unprocessable(detail: impl Into<String>) -> Self {
+        ApiError::Unprocessable {
+            detail: detail.into(),
+            param: None,
+        }
+    }
+
+    pub fn conflict(detail: impl Into<String>) -> Self {
+        ApiError::Conflict {
+            detail: detail.into(),
+        }
+    }
+
+    pub fn with_param(mut self, name: &str) -> Self {
+        match &mut self {
+            ApiError::NotFound { param, .. }
+            | ApiError::BadRequest { param, .. }
+            | ApiError::Unprocessable { param, .. } => *param = Some(name.to_string()),
+            ApiError::Conflict { .. } | ApiError::Internal(_) => {}
+        }
+        self
+    }
+
+    pub fn problem(&self) -> Problem {
+        let (kind, title) = match self {
+            ApiError::NotFound { .. } => ("not-found", "Resource not found"),
+            ApiError::BadRequest { .. } => ("bad-request", "Malformed request"),
+            ApiError::Unprocessable { .. } => ("unprocessable", "Invalid parameter"),
+            ApiError::Conflict { .. } => ("conflict", "Conflicting state"),
+            ApiError::Internal(_) => ("internal", "Internal server error"),
+        };
+        let param = match self {
+            ApiError::NotFound { param, .. }
+            | ApiError::BadRequest { param, .. }
+            | ApiError::Unprocessable { param, .. } => param.clone(),
+            ApiError::Conflict { .. } | ApiError::Internal(_) => None,
+        };
+
+        Problem {
+            kind,
+            title,
+            status: self.status_code().as_u16(),
+            // internal errors carry database and IO messages, which are only logged
+            detail: match self {
+                ApiError::Internal(_) => "the server could not handle the request".to_string(),
+                _ => self.to_string(),
+            },
+            param,
+        }
+    }
+}
+
+impl ResponseError for ApiError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            ApiError::NotFound { .. } => StatusCode::NOT_FOUND,
+            ApiError::BadRequest { .. } => StatusCode::BAD_REQUEST,
+            ApiError::Unprocessable { .. } => StatusCode::UNPROCESSABLE_ENTITY,
+            ApiError::Conflict { .. } => StatusCode::CONFLICT,
+            ApiError::Internal(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        if let ApiError::Internal(e) = self {
+            error!("{}", e);
+        }
+
+        HttpResponse::build(self.status_code())
+            .content_type("application/problem+json")
+            .json(self.problem())
+    }
+}
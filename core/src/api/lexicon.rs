@@ -1,4 +1,5 @@
 pub mod lexicon_controller;
+pub mod lexicon_error;
 pub mod lexicon_model;
 pub mod lexicon_repo;
 pub mod lexicon_service;
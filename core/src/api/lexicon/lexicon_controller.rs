@@ -1,5 +1,11 @@
 use crate::{
-    api::lexicon::lexicon_model::LexiconFilter, error::MapErrActix,
+    api::{
+        error::ApiError,
+        lexicon::lexicon_model::{
+            AnalyzeParams, LexiconFilter, LexiconListFilter, OccurrencesParams, ParadigmParams,
+            SyllablesParams,
+        },
+    },
     utils::extractors::query_nested::QueryNested,
 };
 
@@ -7,33 +13,189 @@ use super::lexicon_service::LexiconService;
 
 use actix_web::{
     get,
-    web::{self, Data},
+    web::{self, Data, Path},
     Responder,
 };
-use anyhow::Context;
 
+#[utoipa::path(
+    context_path = "/v1/lexicon",
+    tag = "lexicon",
+    params(
+        ("prefix" = Option<String>, Query, description = "Start of the lemma, accents, breathings and case ignored"),
+        ("part_of_speech" = Option<PartOfSpeech>, Query, description = "Only entries inflected as this part of speech"),
+        ("declension_type" = Option<DeclensionType>, Query, description = "Only entries of this declension"),
+        ("dialect" = Option<Dialect>, Query, description = "Only entries attested in this dialect"),
+        ("cursor" = Option<String>, Query, description = "`nextCursor` of the previous page"),
+        ("limit" = Option<u32>, Query, description = "Entries per page, 50 by default and 200 at most"),
+    ),
+    responses(
+        (status = 200, body = LexiconPage),
+        (status = 400, description = "Malformed filter", body = Problem),
+    )
+)]
+#[get("")]
+async fn get_lexicon_page(
+    params: QueryNested<LexiconListFilter>,
+    lexicon_service: Data<LexiconService>,
+) -> Result<impl Responder, ApiError> {
+    let page = lexicon_service.find_page(&params).await?;
+
+    Ok(web::Json(page))
+}
+
+#[utoipa::path(
+    context_path = "/v1/lexicon",
+    tag = "lexicon",
+    params(
+        ("lemma" = Option<String>, Query, description = "Lemma of the entry"),
+        ("inflection" = Option<LexiconFilterInflection>, Query, description = "Inflected word and its declension", style = DeepObject, explode),
+        ("mode" = Option<MatchMode>, Query, description = "`loose` ignores accents, breathings and case"),
+    ),
+    responses(
+        (status = 200, body = LexiconEntry),
+        (status = 400, description = "Neither lemma nor inflection was given", body = Problem),
+        (status = 404, description = "No lexicon entry found", body = Problem),
+    )
+)]
 #[get("/find")]
 async fn get_lexicon(
     params: QueryNested<LexiconFilter>,
     lexicon_service: Data<LexiconService>,
-) -> actix_web::Result<impl Responder> {
+) -> Result<impl Responder, ApiError> {
+    if params.lemma.is_none() && params.inflection.is_none() {
+        return Err(
+            ApiError::bad_request("either lemma or inflection must be provided")
+                .with_param("lemma"),
+        );
+    }
+
     let lexicon = lexicon_service
         .find_one(LexiconFilter {
             lemma: params.lemma.to_owned(),
             inflection: params.inflection.to_owned(),
+            mode: params.mode,
         })
-        .await
-        .map_err_actix()?
-        .context("no lexicon entry found")
-        .map_err_actix()?;
+        .await?
+        .ok_or_else(|| ApiError::not_found("no lexicon entry found"))?;
 
     Ok(web::Json(lexicon))
 }
 
+#[utoipa::path(
+    context_path = "/v1/lexicon",
+    tag = "lexicon",
+    params(
+        ("word" = String, Query, description = "Inflected form to analyze"),
+        ("mode" = Option<MatchMode>, Query, description = "`loose` ignores accents, breathings and case"),
+    ),
+    responses(
+        (status = 200, body = [LexiconForm]),
+        (status = 400, description = "No word was given", body = Problem),
+    )
+)]
+#[get("/analyze")]
+async fn analyze_word(
+    params: QueryNested<AnalyzeParams>,
+    lexicon_service: Data<LexiconService>,
+) -> Result<impl Responder, ApiError> {
+    let word = params.word.trim();
+    if word.is_empty() {
+        return Err(ApiError::bad_request("word must not be empty").with_param("word"));
+    }
+
+    let forms = lexicon_service.analyze(word, params.mode).await?;
+
+    Ok(web::Json(forms))
+}
+
+#[utoipa::path(
+    context_path = "/v1/lexicon",
+    tag = "lexicon",
+    params(
+        ("word" = String, Query, description = "Greek word to split into syllables"),
+    ),
+    responses(
+        (status = 200, body = Syllabification),
+        (status = 400, description = "No word was given", body = Problem),
+    )
+)]
+#[get("/syllables")]
+async fn get_syllables(
+    params: QueryNested<SyllablesParams>,
+    lexicon_service: Data<LexiconService>,
+) -> Result<impl Responder, ApiError> {
+    let word = params.word.trim();
+    if word.is_empty() {
+        return Err(ApiError::bad_request("word must not be empty").with_param("word"));
+    }
+
+    Ok(web::Json(lexicon_service.syllabify(word)))
+}
+
+#[utoipa::path(
+    context_path = "/v1/lexicon",
+    tag = "lexicon",
+    params(
+        ("lemma" = String, Path, description = "Lemma of the entry"),
+        ("grid" = Option<bool>, Query, description = "Also lay the forms out in case or person by number tables"),
+        ("mode" = Option<MatchMode>, Query, description = "`loose` ignores accents, breathings and case"),
+    ),
+    responses(
+        (status = 200, body = Paradigm),
+        (status = 404, description = "No lexicon entry found", body = Problem),
+    )
+)]
+#[get("/{lemma}/paradigm")]
+async fn get_paradigm(
+    lemma: Path<String>,
+    params: QueryNested<ParadigmParams>,
+    lexicon_service: Data<LexiconService>,
+) -> Result<impl Responder, ApiError> {
+    let paradigm = lexicon_service
+        .paradigm(&lemma, params.mode, params.grid)
+        .await?
+        .ok_or_else(|| ApiError::not_found("no lexicon entry found").with_param("lemma"))?;
+
+    Ok(web::Json(paradigm))
+}
+
+#[utoipa::path(
+    context_path = "/v1/lexicon",
+    tag = "lexicon",
+    params(
+        ("lemma" = String, Path, description = "Lemma of the entry"),
+        ("mode" = Option<MatchMode>, Query, description = "`loose` ignores accents, breathings and case"),
+        ("offset" = Option<u32>, Query, description = "Occurrences to skip"),
+        ("limit" = Option<u32>, Query, description = "Occurrences to return, 100 by default and 1000 at most"),
+    ),
+    responses(
+        (status = 200, body = LexiconOccurrences),
+        (status = 404, description = "No lexicon entry found", body = Problem),
+    )
+)]
+#[get("/{lemma}/occurrences")]
+async fn get_occurrences(
+    lemma: Path<String>,
+    params: QueryNested<OccurrencesParams>,
+    lexicon_service: Data<LexiconService>,
+) -> Result<impl Responder, ApiError> {
+    let occurrences = lexicon_service
+        .occurrences(&lemma, params.mode, params.offset, params.limit)
+        .await?
+        .ok_or_else(|| ApiError::not_found("no lexicon entry found").with_param("lemma"))?;
+
+    Ok(web::Json(occurrences))
+}
+
 pub fn configure(cfg: &mut web::ServiceConfig) {
     cfg.service(
         web::scope("lexicon")
+            .service(get_lexicon_page)
             .service(get_lexicon)
+            .service(analyze_word)
+            .service(get_syllables)
+            .service(get_paradigm)
+            .service(get_occurrences)
             .app_data(web::Data::new(LexiconService::new())),
     );
 }
@@ -0,0 +1,15 @@
+use thiserror::Error;
+
+use crate::grammar::PartOfSpeech;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum InflectionError {
+    #[error("no inflection found at {0}")]
+    Missing(String),
+    #[error("declension has no {field}, needed after {path}")]
+    Unspecified { path: String, field: &'static str },
+    #[error("part of speech not supported: {0:?}")]
+    UnsupportedPartOfSpeech(PartOfSpeech),
+    #[error("dual is not a supported number for verb inflection, at {0}")]
+    DualVerb(String),
+}
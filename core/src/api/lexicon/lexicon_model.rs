@@ -1,34 +1,45 @@
 use serde::{Deserialize, Serialize};
 use strum::Display;
+use utoipa::ToSchema;
 
-use crate::grammar::{Declension, DeclensionType, Dialect};
+use crate::{
+    api::verse::verse_model::WordOccurrence,
+    grammar::{Declension, DeclensionType, Dialect, PartOfSpeech},
+    texts::{Book, Collection},
+    utils::str::syllables::{Position, Quantity},
+};
 
 #[serde_with::skip_serializing_none]
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
 pub struct LexiconEntry {
     pub lemma: String,
+    #[serde(default)]
+    pub lemma_key: Option<String>,
     pub inflections: Vec<WordInflection>,
     pub definitions: Vec<LexiconEntryDefinition>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone, Display)]
+#[derive(Debug, Serialize, Deserialize, Clone, Display, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub enum LexiconEntryDefinition {
     Litteral(String),
     FormOf(DefinitionFormOf),
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
 pub struct DefinitionFormOf {
     pub lemma: String,
     pub text: String,
 }
 
 #[serde_with::skip_serializing_none]
-#[derive(Serialize, Deserialize, Default, Debug, Clone, Hash, PartialEq)]
+#[derive(Serialize, Deserialize, Default, Debug, Clone, Hash, PartialEq, ToSchema)]
 pub struct WordInflection {
     pub dialects: Vec<Dialect>,
     pub declension_type: Option<DeclensionType>,
+    /// Part of speech the table was imported for, which tells the kinds of pronoun, article and
+    /// numeral apart where the table alone doesn't.
+    pub part_of_speech: Option<PartOfSpeech>,
     pub noun: Option<Box<NounInflectionGenders>>,
     pub article: Option<Box<NounInflectionGenders>>,
     pub pronoun: Option<Box<NounInflectionGenders>>,
@@ -42,7 +53,7 @@ pub struct WordInflection {
 }
 
 #[serde_with::skip_serializing_none]
-#[derive(Serialize, Deserialize, Default, Debug, Clone, Hash, PartialEq)]
+#[derive(Serialize, Deserialize, Default, Debug, Clone, Hash, PartialEq, ToSchema)]
 pub struct WordAdjective {
     pub positive: Option<Box<NounInflectionGenders>>,
     pub comparative: Option<Box<NounInflectionGenders>>,
@@ -50,7 +61,7 @@ pub struct WordAdjective {
 }
 
 #[serde_with::skip_serializing_none]
-#[derive(Serialize, Deserialize, Default, Debug, Clone, Hash, PartialEq)]
+#[derive(Serialize, Deserialize, Default, Debug, Clone, Hash, PartialEq, ToSchema)]
 pub struct VerbInflectionTenses {
     pub present: Option<Box<VerbInflectionThemes>>,
     pub imperfect: Option<Box<VerbInflectionThemes>>,
@@ -64,21 +75,21 @@ pub struct VerbInflectionTenses {
 }
 
 #[serde_with::skip_serializing_none]
-#[derive(Serialize, Deserialize, Default, Debug, Clone, Hash, PartialEq)]
+#[derive(Serialize, Deserialize, Default, Debug, Clone, Hash, PartialEq, ToSchema)]
 pub struct VerbInflectionThemes {
     pub thematic: Option<VerbInflectionContractions>,
     pub athematic: Option<VerbInflectionContractions>,
 }
 
 #[serde_with::skip_serializing_none]
-#[derive(Serialize, Deserialize, Default, Debug, Clone, Hash, PartialEq)]
+#[derive(Serialize, Deserialize, Default, Debug, Clone, Hash, PartialEq, ToSchema)]
 pub struct VerbInflectionContractions {
     pub contracted: Option<VerbInflectionMoods>,
     pub uncontracted: Option<VerbInflectionMoods>,
 }
 
 #[serde_with::skip_serializing_none]
-#[derive(Serialize, Deserialize, Default, Debug, Clone, Hash, PartialEq)]
+#[derive(Serialize, Deserialize, Default, Debug, Clone, Hash, PartialEq, ToSchema)]
 pub struct VerbInflectionMoods {
     pub indicative: Option<VerbInflectionVoices>,
     pub subjunctive: Option<VerbInflectionVoices>,
@@ -90,7 +101,7 @@ pub struct VerbInflectionMoods {
 }
 
 #[serde_with::skip_serializing_none]
-#[derive(Serialize, Deserialize, Default, Debug, Clone, Hash, PartialEq)]
+#[derive(Serialize, Deserialize, Default, Debug, Clone, Hash, PartialEq, ToSchema)]
 pub struct VerbInflectionInfinitive {
     pub active: Option<Vec<InflectionForm>>,
     pub middle: Option<Vec<InflectionForm>>,
@@ -98,7 +109,7 @@ pub struct VerbInflectionInfinitive {
 }
 
 #[serde_with::skip_serializing_none]
-#[derive(Serialize, Deserialize, Default, Debug, Clone, Hash, PartialEq)]
+#[derive(Serialize, Deserialize, Default, Debug, Clone, Hash, PartialEq, ToSchema)]
 pub struct VerbInflectionParticiple {
     pub active: Option<NounInflectionGenders>,
     pub middle: Option<NounInflectionGenders>,
@@ -106,7 +117,7 @@ pub struct VerbInflectionParticiple {
 }
 
 #[serde_with::skip_serializing_none]
-#[derive(Serialize, Deserialize, Default, Debug, Clone, Hash, PartialEq)]
+#[derive(Serialize, Deserialize, Default, Debug, Clone, Hash, PartialEq, ToSchema)]
 pub struct VerbInflectionVoices {
     pub active: Option<VerbInflectionNumbers>,
     pub middle: Option<VerbInflectionNumbers>,
@@ -114,7 +125,7 @@ pub struct VerbInflectionVoices {
 }
 
 #[serde_with::skip_serializing_none]
-#[derive(Serialize, Deserialize, Default, Debug, Clone, Hash, PartialEq)]
+#[derive(Serialize, Deserialize, Default, Debug, Clone, Hash, PartialEq, ToSchema)]
 pub struct VerbInflectionNumbers {
     pub singular: Option<VerbInflectionPersons>,
     pub plural: Option<VerbInflectionPersons>,
@@ -122,7 +133,7 @@ pub struct VerbInflectionNumbers {
 }
 
 #[serde_with::skip_serializing_none]
-#[derive(Serialize, Deserialize, Default, Debug, Clone, Hash, PartialEq)]
+#[derive(Serialize, Deserialize, Default, Debug, Clone, Hash, PartialEq, ToSchema)]
 pub struct VerbInflectionPersons {
     pub first: Option<Vec<InflectionForm>>,
     pub second: Option<Vec<InflectionForm>>,
@@ -130,14 +141,14 @@ pub struct VerbInflectionPersons {
 }
 
 #[serde_with::skip_serializing_none]
-#[derive(Serialize, Deserialize, Default, Debug, Clone, Hash, PartialEq)]
+#[derive(Serialize, Deserialize, Default, Debug, Clone, Hash, PartialEq, ToSchema)]
 pub struct InflectionForm {
     pub contracted: Option<String>,
     pub uncontracted: Option<Vec<String>>,
 }
 
 #[serde_with::skip_serializing_none]
-#[derive(Serialize, Deserialize, Default, Debug, Clone, Hash, PartialEq)]
+#[derive(Serialize, Deserialize, Default, Debug, Clone, Hash, PartialEq, ToSchema)]
 pub struct NounInflectionGenders {
     pub masculine: Option<NounInflectionNumbers>,
     pub feminine: Option<NounInflectionNumbers>,
@@ -145,7 +156,7 @@ pub struct NounInflectionGenders {
 }
 
 #[serde_with::skip_serializing_none]
-#[derive(Serialize, Deserialize, Default, Debug, Clone, Hash, PartialEq)]
+#[derive(Serialize, Deserialize, Default, Debug, Clone, Hash, PartialEq, ToSchema)]
 pub struct NounInflectionNumbers {
     pub singular: Option<NounInflectionCases>,
     pub dual: Option<NounInflectionCases>,
@@ -153,7 +164,7 @@ pub struct NounInflectionNumbers {
 }
 
 #[serde_with::skip_serializing_none]
-#[derive(Serialize, Deserialize, Default, Debug, Clone, Hash, PartialEq)]
+#[derive(Serialize, Deserialize, Default, Debug, Clone, Hash, PartialEq, ToSchema)]
 pub struct NounInflectionCases {
     pub nominative: Option<Vec<InflectionForm>>,
     pub genitive: Option<Vec<InflectionForm>>,
@@ -162,14 +173,136 @@ pub struct NounInflectionCases {
     pub vocative: Option<Vec<InflectionForm>>,
 }
 
-#[derive(Debug, Default, Serialize, Deserialize, Clone)]
+#[derive(Debug, Default, PartialEq, Clone, Copy, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum MatchMode {
+    #[default]
+    Strict,
+    Loose,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize, Clone, ToSchema)]
 pub struct LexiconFilter {
     pub lemma: Option<String>,
     pub inflection: Option<LexiconFilterInflection>,
+    #[serde(default)]
+    pub mode: MatchMode,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
 pub struct LexiconFilterInflection {
     pub word: String,
     pub declension: Declension,
 }
+
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+pub struct LexiconForm {
+    pub form: String,
+    pub form_key: String,
+    pub lemma: String,
+    pub declension: Declension,
+    pub dialect: Option<Dialect>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AnalyzeParams {
+    pub word: String,
+    #[serde(default)]
+    pub mode: MatchMode,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SyllablesParams {
+    pub word: String,
+}
+
+#[derive(Debug, Serialize, Clone, ToSchema)]
+pub struct Syllabification {
+    pub word: String,
+    pub syllables: Vec<WordSyllable>,
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Serialize, Clone, ToSchema)]
+pub struct WordSyllable {
+    pub text: String,
+    /// Vowel or diphthong of the syllable, without diacritics
+    pub nucleus: String,
+    pub quantity: Quantity,
+    /// Set on the last three syllables, the only ones an accent can fall on
+    pub position: Option<Position>,
+}
+
+#[derive(Debug, Serialize, Clone, ToSchema)]
+pub struct ParadigmRow {
+    pub declension: Declension,
+    pub forms: Vec<String>,
+    pub dialects: Vec<Dialect>,
+}
+
+#[derive(Debug, Serialize, Clone, ToSchema)]
+pub struct ParadigmTable {
+    pub declension: Declension,
+    pub dialects: Vec<Dialect>,
+    pub rows: Vec<String>,
+    pub columns: Vec<String>,
+    pub cells: Vec<Vec<Vec<String>>>,
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Serialize, Clone, ToSchema)]
+pub struct Paradigm {
+    pub lemma: String,
+    pub rows: Vec<ParadigmRow>,
+    pub tables: Option<Vec<ParadigmTable>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ParadigmParams {
+    #[serde(default)]
+    pub grid: bool,
+    #[serde(default)]
+    pub mode: MatchMode,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct OccurrencesParams {
+    #[serde(default)]
+    pub mode: MatchMode,
+    pub offset: Option<u32>,
+    pub limit: Option<u32>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct LexiconListFilter {
+    pub prefix: Option<String>,
+    pub part_of_speech: Option<PartOfSpeech>,
+    pub declension_type: Option<DeclensionType>,
+    pub dialect: Option<Dialect>,
+    pub cursor: Option<String>,
+    pub limit: Option<u32>,
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Serialize, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct LexiconPage {
+    pub entries: Vec<LexiconEntry>,
+    pub next_cursor: Option<String>,
+}
+
+#[derive(Debug, Serialize, Clone, ToSchema)]
+pub struct BookOccurrences {
+    pub collection: Collection,
+    pub book: Book,
+    pub count: u32,
+}
+
+#[derive(Debug, Serialize, Clone, ToSchema)]
+pub struct LexiconOccurrences {
+    pub lemma: String,
+    pub total: u32,
+    pub books: Vec<BookOccurrences>,
+    pub occurrences: Vec<WordOccurrence>,
+}
@@ -1,26 +1,38 @@
+use futures::TryStreamExt;
 use mongodb::{
     bson::{doc, Document},
-    options::IndexOptions,
+    options::{Collation, FindOptions, IndexOptions},
     Collection, IndexModel,
 };
-use nameof::name_of;
 
 use crate::{
     borrow::Cow,
     error::{MapErrSafe, SafeError},
     grammar::{Declension, Mood, Numeral, PartOfSpeech},
     persistence::get_db,
-    utils::str::{camel_case::CamelCase, snake_case::SnakeCase},
+    utils::str::{search_key::search_key, snake_case::SnakeCase},
 };
 
-use super::lexicon_model::{LexiconEntry, LexiconFilter};
+use super::lexicon_model::{
+    LexiconEntry, LexiconFilter, LexiconForm, LexiconListFilter, MatchMode,
+};
 
 pub struct LexiconRepo;
 
 impl LexiconRepo {
     pub const COLLECTION_NAME: &'static str = "lexicon";
+    pub const FORMS_COLLECTION_NAME: &'static str = "lexicon_forms";
 
     pub async fn find_one(filter: LexiconFilter) -> Result<Option<LexiconEntry>, SafeError> {
+        // several entries can share a search key: an exact match wins, then the first lemma,
+        // rather than whichever Mongo returns first
+        if filter.mode == MatchMode::Loose {
+            let entries = LexiconRepo::find_many(filter.clone()).await?;
+            return Ok(entries.into_iter().min_by(|a, b| {
+                (!filter.is_exact(a), &a.lemma).cmp(&(!filter.is_exact(b), &b.lemma))
+            }));
+        }
+
         get_collection()
             .await?
             .find_one(filter.to_document()?, None)
@@ -28,13 +40,129 @@ impl LexiconRepo {
             .map_err_safe()
     }
 
+    pub async fn find_many(filter: LexiconFilter) -> Result<Vec<LexiconEntry>, SafeError> {
+        let mut doc = filter.to_document()?;
+
+        let loose_inflection = match (&filter.inflection, filter.mode) {
+            (Some(inflection), MatchMode::Loose) => Some(inflection),
+            _ => None,
+        };
+        if let Some(inflection) = loose_inflection {
+            let lemmas = LexiconRepo::find_forms(&inflection.word, MatchMode::Loose)
+                .await?
+                .into_iter()
+                .map(|form| form.lemma)
+                .collect::<Vec<_>>();
+            doc.insert("lemma", doc! {"$in": lemmas});
+        }
+
+        let mut entries: Vec<LexiconEntry> = get_collection()
+            .await?
+            .find(doc, None)
+            .await
+            .map_err_safe()?
+            .try_collect()
+            .await
+            .map_err_safe()?;
+
+        if let Some(inflection) = loose_inflection {
+            let key = search_key(&inflection.word);
+            entries.retain(|entry| {
+                entry.inflections.iter().any(|x| {
+                    x.find_inflection(&inflection.declension)
+                        .is_ok_and(|forms| forms.iter().any(|form| search_key(form) == key))
+                })
+            });
+        }
+
+        Ok(entries)
+    }
+
+    pub async fn find_page(filter: &LexiconListFilter) -> Result<Vec<LexiconEntry>, SafeError> {
+        let options = FindOptions::builder()
+            .sort(doc! {"lemma": 1})
+            .collation(greek_collation())
+            .limit(filter.limit() as i64)
+            .build();
+
+        get_collection()
+            .await?
+            .find(Document::from(filter), options)
+            .await
+            .map_err_safe()?
+            .try_collect()
+            .await
+            .map_err_safe()
+    }
+
     pub async fn insert_many(entries: &[LexiconEntry]) -> Result<(), SafeError> {
+        let entries = entries
+            .iter()
+            .cloned()
+            .map(|mut entry| {
+                entry.lemma_key = Some(search_key(&entry.lemma));
+                entry
+            })
+            .collect::<Vec<_>>();
+
         get_collection()
             .await?
-            .insert_many(entries, None)
+            .insert_many(&entries, None)
             .await
             .map_err_safe()?;
 
+        LexiconRepo::index_forms(&entries).await
+    }
+
+    pub async fn update_lemma_keys(entries: &[LexiconEntry]) -> Result<(), SafeError> {
+        let collection = get_collection().await?;
+
+        for entry in entries {
+            collection
+                .update_one(
+                    doc! {"lemma": &entry.lemma},
+                    doc! {"$set": {"lemma_key": search_key(&entry.lemma)}},
+                    None,
+                )
+                .await
+                .map_err_safe()?;
+        }
+
+        Ok(())
+    }
+
+    pub async fn find_forms(word: &str, mode: MatchMode) -> Result<Vec<LexiconForm>, SafeError> {
+        let options = FindOptions::builder().sort(doc! {"lemma": 1}).build();
+        let filter = match mode {
+            MatchMode::Strict => doc! {"form": word.to_lowercase()},
+            MatchMode::Loose => doc! {"form_key": search_key(word)},
+        };
+
+        get_forms_collection()
+            .await?
+            .find(filter, options)
+            .await
+            .map_err_safe()?
+            .try_collect()
+            .await
+            .map_err_safe()
+    }
+
+    pub async fn index_forms(entries: &[LexiconEntry]) -> Result<(), SafeError> {
+        let collection = get_forms_collection().await?;
+
+        for entry in entries {
+            collection
+                .delete_many(doc! {"lemma": &entry.lemma}, None)
+                .await
+                .map_err_safe()?;
+
+            let forms = entry.forms();
+            if !forms.is_empty() {
+                collection.insert_many(forms, None).await.map_err_safe()?;
+            }
+        }
+
         Ok(())
     }
 
@@ -43,25 +171,54 @@ impl LexiconRepo {
     }
 }
 
+fn greek_collation() -> Collation {
+    Collation::builder().locale("el").build()
+}
+
 async fn get_collection() -> Result<Collection<LexiconEntry>, SafeError> {
     Ok(get_db()
         .await?
         .collection::<LexiconEntry>(LexiconRepo::COLLECTION_NAME))
 }
 
+async fn get_forms_collection() -> Result<Collection<LexiconForm>, SafeError> {
+    Ok(get_db()
+        .await?
+        .collection::<LexiconForm>(LexiconRepo::FORMS_COLLECTION_NAME))
+}
+
 pub async fn configure() -> Result<(), SafeError> {
     let options = IndexOptions::builder().unique(true).build();
     let unique_key_lemma = IndexModel::builder()
         .keys(doc! {"lemma": 1})
         .options(options)
         .build();
+    let lemma_key = IndexModel::builder().keys(doc! {"lemma_key": 1}).build();
+    let lemma_sort = IndexModel::builder()
+        .keys(doc! {"lemma": 1})
+        .options(
+            IndexOptions::builder()
+                .name("lemma_el".to_string())
+                .collation(greek_collation())
+                .build(),
+        )
+        .build();
 
     get_collection()
         .await?
-        .create_index(unique_key_lemma, None)
+        .create_indexes([unique_key_lemma, lemma_key, lemma_sort], None)
         .await
         .expect("error creating index!");
 
+    let forms_keys = [doc! {"form": 1}, doc! {"form_key": 1}, doc! {"lemma": 1}];
+    for keys in forms_keys {
+        get_forms_collection()
+            .await?
+            .create_index(IndexModel::builder().keys(keys).build(), None)
+            .await
+            .expect("error creating index!");
+    }
+
     Ok(())
 }
 
@@ -70,7 +227,7 @@ fn fill_query(doc: &mut Document, stages: &Vec<Vec<String>>, i: usize, word: &st
     if i == stages.len() - 1 {
         doc.insert(
             stage.join("."),
-            doc! {"$regex": word.to_string(), "$options": "i"},
+            doc! {"$regex": format!("^{}$", regex::escape(word)), "$options": "i"},
         );
     } else {
         let mut subdoc = doc! {};
@@ -83,13 +240,20 @@ impl LexiconFilter {
     pub fn to_document(&self) -> Result<Document, SafeError> {
         let mut doc = Document::new();
 
-        let regex_match = |x| doc! {"$regex": format!("^{}$", x), "$options": "i"};
+        let lemma_match = |doc: &mut Document, x: &str| match self.mode {
+            MatchMode::Strict => doc.insert(
+                "lemma",
+                doc! {"$regex": format!("^{}$", regex::escape(x)), "$options": "i"},
+            ),
+            MatchMode::Loose => doc.insert("lemma_key", search_key(x)),
+        };
 
         if let Some(lemma) = &self.lemma {
-            doc.insert(name_of!(lemma).camel_case(), regex_match(lemma));
+            lemma_match(&mut doc, lemma);
         }
 
-        if let Some(inflection) = &self.inflection {
+        // loose inflections are matched against the form index in find_many
+        if let (Some(inflection), MatchMode::Strict) = (&self.inflection, self.mode) {
             if let Some(key) = inflection.declension.to_inflection_key()? {
                 let key = format!("inflections.[].{}", key);
                 let mut stages = Vec::<Vec<String>>::new();
@@ -106,12 +270,23 @@ impl LexiconFilter {
 
                 fill_query(&mut doc, &stages, 0, &inflection.word);
             } else {
-                doc.insert("lemma", regex_match(&inflection.word));
+                lemma_match(&mut doc, &inflection.word);
             }
         }
 
         Ok(doc)
     }
+
+    /// Whether `entry` has the lemma or the inflected form as written, accents and all.
+    fn is_exact(&self, entry: &LexiconEntry) -> bool {
+        self.lemma.as_ref().is_some_and(|x| entry.lemma == *x)
+            || self.inflection.as_ref().is_some_and(|inflection| {
+                entry.inflections.iter().any(|x| {
+                    x.find_inflection(&inflection.declension)
+                        .is_ok_and(|forms| forms.contains(&inflection.word))
+                })
+            })
+    }
 }
 
 fn str(s: &impl ToString) -> Cow<str> {
@@ -241,9 +416,49 @@ impl Declension {
             s.push("[]".into());
             s.push("contracted".into());
         } else {
-            panic!("part of speech not supported: {:?}", self.part_of_speech);
+            // no table to look into, so the word is matched as a lemma, as an interjection is
+            return Ok(None);
         }
 
         Ok(Some(s.join(".")))
     }
 }
+
+impl LexiconListFilter {
+    pub const DEFAULT_LIMIT: u32 = 50;
+    pub const MAX_LIMIT: u32 = 200;
+
+    pub fn limit(&self) -> u32 {
+        self.limit
+            .unwrap_or(Self::DEFAULT_LIMIT)
+            .clamp(1, Self::MAX_LIMIT)
+    }
+}
+
+impl From<&LexiconListFilter> for Document {
+    fn from(value: &LexiconListFilter) -> Self {
+        let mut doc = Document::new();
+
+        if let Some(prefix) = &value.prefix {
+            let prefix = format!("^{}", regex::escape(&search_key(prefix)));
+            doc.insert("lemma_key", doc! {"$regex": prefix});
+        }
+        if let Some(part_of_speech) = &value.part_of_speech {
+            doc.insert(
+                format!("inflections.{}", part_of_speech),
+                doc! {"$exists": true},
+            );
+        }
+        if let Some(declension_type) = &value.declension_type {
+            doc.insert("inflections.declension_type", declension_type.to_string());
+        }
+        if let Some(dialect) = &value.dialect {
+            doc.insert("inflections.dialects", dialect.to_string());
+        }
+        if let Some(cursor) = &value.cursor {
+            doc.insert("lemma", doc! {"$gt": cursor});
+        }
+
+        doc
+    }
+}
@@ -1,16 +1,26 @@
+use std::mem;
+
 use crate::{
+    api::verse::{verse_model::WordQuery, verse_repo::VerseRepo, verse_service::VerseService},
     error::SafeError,
     grammar::{
-        Case, Contraction, Declension, Gender, Mood, Number, PartOfSpeech, Person, Tense, Theme,
-        Voice,
+        Adjective, Article, Case, Contraction, Declension, Dialect, Gender, Mood, Noun, Number,
+        Numeral, PartOfSpeech, Person, Pronoun, Tense, Theme, Voice,
+    },
+    utils::str::{
+        search_key::search_key,
+        syllables::{syllables, Syllables},
     },
 };
 
 use super::{
+    lexicon_error::InflectionError,
     lexicon_model::{
-        InflectionForm, LexiconEntry, LexiconFilter, NounInflectionGenders,
-        VerbInflectionInfinitive, VerbInflectionParticiple, VerbInflectionTenses, WordAdjective,
-        WordInflection,
+        BookOccurrences, InflectionForm, LexiconEntry, LexiconFilter, LexiconForm,
+        LexiconListFilter, LexiconOccurrences, LexiconPage, MatchMode, NounInflectionGenders,
+        Paradigm, ParadigmRow, ParadigmTable, Syllabification, VerbInflectionInfinitive,
+        VerbInflectionMoods, VerbInflectionParticiple, VerbInflectionTenses, WordAdjective,
+        WordInflection, WordSyllable,
     },
     lexicon_repo::LexiconRepo,
 };
@@ -25,197 +35,745 @@ impl LexiconService {
     pub async fn find_one(&self, filter: LexiconFilter) -> Result<Option<LexiconEntry>, SafeError> {
         LexiconRepo::find_one(filter).await
     }
+
+    pub async fn find_page(&self, filter: &LexiconListFilter) -> Result<LexiconPage, SafeError> {
+        let entries = LexiconRepo::find_page(filter).await?;
+        let next_cursor = match entries.len() == filter.limit() as usize {
+            true => entries.last().map(|entry| entry.lemma.clone()),
+            false => None,
+        };
+
+        Ok(LexiconPage {
+            entries,
+            next_cursor,
+        })
+    }
+
+    pub async fn analyze(
+        &self,
+        word: &str,
+        mode: MatchMode,
+    ) -> Result<Vec<LexiconForm>, SafeError> {
+        LexiconRepo::find_forms(word, mode).await
+    }
+
+    pub fn syllabify(&self, word: &str) -> Syllabification {
+        let syllables = syllables(word);
+        let positions = (0..syllables.len()).map(|i| syllables.position(i));
+
+        Syllabification {
+            word: word.to_string(),
+            syllables: syllables
+                .iter()
+                .zip(positions)
+                .map(|(syllable, position)| WordSyllable {
+                    text: syllable.text.clone(),
+                    nucleus: syllable.nucleus.clone(),
+                    quantity: syllable.quantity,
+                    position,
+                })
+                .collect(),
+        }
+    }
+
+    pub async fn paradigm(
+        &self,
+        lemma: &str,
+        mode: MatchMode,
+        grid: bool,
+    ) -> Result<Option<Paradigm>, SafeError> {
+        let entry = LexiconRepo::find_one(LexiconFilter {
+            lemma: Some(lemma.to_string()),
+            mode,
+            ..Default::default()
+        })
+        .await?;
+
+        Ok(entry.map(|entry| entry.paradigm(grid)))
+    }
+
+    pub async fn occurrences(
+        &self,
+        lemma: &str,
+        mode: MatchMode,
+        offset: Option<u32>,
+        limit: Option<u32>,
+    ) -> Result<Option<LexiconOccurrences>, SafeError> {
+        let entry = LexiconRepo::find_one(LexiconFilter {
+            lemma: Some(lemma.to_string()),
+            mode,
+            ..Default::default()
+        })
+        .await?;
+        let Some(entry) = entry else {
+            return Ok(None);
+        };
+
+        let query = WordQuery {
+            lemma: Some(entry.lemma.clone()),
+            offset,
+            limit,
+            ..Default::default()
+        };
+        let books = VerseRepo::count_words(&query)
+            .await?
+            .into_iter()
+            .map(|x| BookOccurrences {
+                collection: x.book.collection,
+                book: x.book.book,
+                count: x.words as u32,
+            })
+            .collect::<Vec<_>>();
+        let occurrences = VerseService::find_words(query).await?;
+
+        Ok(Some(LexiconOccurrences {
+            lemma: entry.lemma,
+            total: books.iter().map(|x| x.count).sum(),
+            books,
+            occurrences,
+        }))
+    }
+
+    pub async fn rebuild_search_index() -> Result<usize, SafeError> {
+        let entries = LexiconRepo::find_many(LexiconFilter::default()).await?;
+        LexiconRepo::update_lemma_keys(&entries).await?;
+        LexiconRepo::index_forms(&entries).await?;
+        Ok(entries.len())
+    }
+}
+
+impl LexiconEntry {
+    pub fn forms(&self) -> Vec<LexiconForm> {
+        let mut forms = Vec::new();
+
+        for inflection in &self.inflections {
+            let mut found = Vec::new();
+            inflection.collect_forms(&mut found);
+
+            let dialects = match inflection.dialects.is_empty() {
+                true => vec![None],
+                false => inflection.dialects.iter().copied().map(Some).collect(),
+            };
+            for (form, declension) in found {
+                for dialect in &dialects {
+                    forms.push(LexiconForm {
+                        form: form.to_lowercase(),
+                        form_key: search_key(&form),
+                        lemma: self.lemma.clone(),
+                        declension: declension.clone(),
+                        dialect: *dialect,
+                    });
+                }
+            }
+        }
+
+        forms
+    }
+
+    pub fn paradigm(&self, grid: bool) -> Paradigm {
+        let mut rows = Vec::new();
+        let mut tables = Vec::new();
+
+        for inflection in &self.inflections {
+            let mut found = Vec::new();
+            inflection.collect_forms(&mut found);
+
+            let start = rows.len();
+            for (form, declension) in found {
+                let existing = rows[start..]
+                    .iter_mut()
+                    .find(|row: &&mut ParadigmRow| row.declension == declension);
+                match existing {
+                    Some(row) => row.forms.push(form),
+                    None => rows.push(ParadigmRow {
+                        declension,
+                        forms: vec![form],
+                        dialects: inflection.dialects.clone(),
+                    }),
+                }
+            }
+
+            if grid {
+                tables.extend(paradigm_tables(&rows[start..], &inflection.dialects));
+            }
+        }
+
+        Paradigm {
+            lemma: self.lemma.clone(),
+            rows,
+            tables: grid.then_some(tables),
+        }
+    }
+}
+
+const TABLE_ROWS: [&str; 8] = [
+    "nominative",
+    "genitive",
+    "dative",
+    "accusative",
+    "vocative",
+    "first",
+    "second",
+    "third",
+];
+const TABLE_COLUMNS: [&str; 3] = ["singular", "dual", "plural"];
+
+struct TableCell {
+    row: String,
+    column: String,
+    forms: Vec<String>,
+}
+
+fn paradigm_tables(rows: &[ParadigmRow], dialects: &[Dialect]) -> Vec<ParadigmTable> {
+    let mut tables = Vec::<(Declension, Vec<TableCell>)>::new();
+
+    for row in rows {
+        let decl = &row.declension;
+        let label = decl
+            .case
+            .map(|x| x.to_string())
+            .or(decl.person.map(|x| x.to_string()));
+        let (Some(number), Some(label)) = (decl.number, label) else {
+            continue;
+        };
+        let key = Declension {
+            number: None,
+            case: None,
+            person: None,
+            ..decl.clone()
+        };
+        let cell = TableCell {
+            row: label,
+            column: number.to_string(),
+            forms: row.forms.clone(),
+        };
+
+        match tables.iter_mut().find(|(k, _)| *k == key) {
+            Some((_, cells)) => cells.push(cell),
+            None => tables.push((key, vec![cell])),
+        }
+    }
+
+    tables
+        .into_iter()
+        .map(|(declension, cells)| {
+            let rows = ordered_labels(&TABLE_ROWS, cells.iter().map(|x| &x.row));
+            let columns = ordered_labels(&TABLE_COLUMNS, cells.iter().map(|x| &x.column));
+            let grid = rows
+                .iter()
+                .map(|row| {
+                    columns
+                        .iter()
+                        .map(|column| {
+                            cells
+                                .iter()
+                                .find(|x| &x.row == row && &x.column == column)
+                                .map(|x| x.forms.clone())
+                                .unwrap_or_default()
+                        })
+                        .collect()
+                })
+                .collect();
+
+            ParadigmTable {
+                declension,
+                dialects: dialects.to_vec(),
+                rows,
+                columns,
+                cells: grid,
+            }
+        })
+        .collect()
+}
+
+fn ordered_labels<'a>(order: &[&str], labels: impl Iterator<Item = &'a String>) -> Vec<String> {
+    let labels = labels.collect::<Vec<_>>();
+    order
+        .iter()
+        .filter(|x| labels.iter().any(|label| label == *x))
+        .map(|x| x.to_string())
+        .collect()
 }
 
 impl WordInflection {
-    pub fn find_inflection(&self, declension: &Declension) -> Vec<String> {
-        if matches!(declension.part_of_speech, PartOfSpeech::Noun(_)) {
-            let noun = self.noun.as_ref().unwrap();
-
-            find_inflection_noun(declension, noun)
-        } else if matches!(declension.part_of_speech, PartOfSpeech::Verb) {
-            let verb = self.verb.as_ref().unwrap();
-
-            return find_inflection_verb(declension, verb);
-        } else if matches!(declension.part_of_speech, PartOfSpeech::Article(_)) {
-            let article = self.article.as_ref().unwrap();
-
-            return find_inflection_noun(declension, article);
-        } else if matches!(declension.part_of_speech, PartOfSpeech::Pronoun(_)) {
-            let pronoun = self.pronoun.as_ref().unwrap();
-
-            return find_inflection_noun(declension, pronoun);
-        } else if matches!(declension.part_of_speech, PartOfSpeech::Quantifier) {
-            let quantifier = self.quantifier.as_ref().unwrap();
-
-            return find_inflection_noun(declension, quantifier);
-        } else if matches!(declension.part_of_speech, PartOfSpeech::Particle) {
-            let particle = self.particle.as_ref().unwrap();
-
-            return find_inflection_form(particle);
-        } else if matches!(declension.part_of_speech, PartOfSpeech::Preposition) {
-            let preposition = self.preposition.as_ref().unwrap();
-
-            return find_inflection_form(preposition);
-        } else if matches!(declension.part_of_speech, PartOfSpeech::Adverb) {
-            let adverb = self.adverb.as_ref().unwrap();
-
-            return find_inflection_form(adverb);
-        } else if matches!(declension.part_of_speech, PartOfSpeech::Numeral(_)) {
-            let numeral = self.numeral.as_ref().unwrap();
-
-            return find_inflection_noun(declension, numeral);
-        } else if matches!(declension.part_of_speech, PartOfSpeech::Adjective(_)) {
-            let adjective = self.adjective.as_ref().unwrap();
-
-            return find_inflection_adjective(declension, adjective);
-        } else {
-            panic!(
-                "Unsupported part of speech: {:?}",
-                declension.part_of_speech
-            );
-        }
-    }
-}
-
-fn find_inflection_verb(declension: &Declension, verb: &VerbInflectionTenses) -> Vec<String> {
-    let tense = match declension.tense {
-        Some(Tense::Aorist) => verb.aorist.as_ref().unwrap(),
-        Some(Tense::Future) => verb.future.as_ref().unwrap(),
-        Some(Tense::FuturePerfect) => verb.future_perfect.as_ref().unwrap(),
-        Some(Tense::Imperfect) => verb.imperfect.as_ref().unwrap(),
-        Some(Tense::Perfect) => verb.perfect.as_ref().unwrap(),
-        Some(Tense::Pluperfect) => verb.pluperfect.as_ref().unwrap(),
-        Some(Tense::Present) => verb.present.as_ref().unwrap(),
-        None => panic!("No tense found for {:?}", declension),
-    };
-    let theme = match declension.theme {
-        Some(Theme::Athematic) => tense.athematic.as_ref().unwrap(),
-        Some(Theme::Thematic) | None => tense.thematic.as_ref().unwrap(),
-    };
-    let contraction = match declension.contraction {
-        Some(Contraction::Uncontracted) => theme.uncontracted.as_ref().unwrap(),
-        Some(Contraction::Contracted) | None => theme.contracted.as_ref().unwrap(),
-    };
-    let mood = match declension.mood {
-        Some(mood) => match mood {
-            Mood::Indicative => contraction.indicative.as_ref().unwrap(),
-            Mood::Imperative => contraction.imperative.as_ref().unwrap(),
-            Mood::Optative => contraction.optative.as_ref().unwrap(),
-            Mood::Subjunctive => contraction.subjunctive.as_ref().unwrap(),
-            Mood::Infinitive => {
-                return find_inflection_verb_infinitive(
-                    declension,
-                    contraction.infinitive.as_ref().unwrap(),
-                )
-            }
-            Mood::Participle => {
-                return find_inflection_verb_participle(
-                    declension,
-                    contraction.participle.as_ref().unwrap(),
-                )
+    pub fn find_inflection(&self, declension: &Declension) -> Result<Vec<String>, InflectionError> {
+        let path = declension.part_of_speech.to_string();
+
+        match declension.part_of_speech {
+            PartOfSpeech::Noun(_) => {
+                find_inflection_noun(declension, field(&self.noun, &path)?, &path)
+            }
+            PartOfSpeech::Verb => find_inflection_verb(declension, field(&self.verb, &path)?),
+            PartOfSpeech::Article(_) => {
+                find_inflection_noun(declension, field(&self.article, &path)?, &path)
+            }
+            PartOfSpeech::Pronoun(_) => {
+                find_inflection_noun(declension, field(&self.pronoun, &path)?, &path)
+            }
+            PartOfSpeech::Quantifier => {
+                find_inflection_noun(declension, field(&self.quantifier, &path)?, &path)
+            }
+            PartOfSpeech::Particle => Ok(find_inflection_form(field(&self.particle, &path)?)),
+            PartOfSpeech::Preposition => Ok(find_inflection_form(field(&self.preposition, &path)?)),
+            PartOfSpeech::Adverb => Ok(find_inflection_form(field(&self.adverb, &path)?)),
+            PartOfSpeech::Numeral(_) => {
+                find_inflection_noun(declension, field(&self.numeral, &path)?, &path)
+            }
+            PartOfSpeech::Adjective(adj) => {
+                find_inflection_adjective(adj, declension, field(&self.adjective, &path)?, &path)
+            }
+            PartOfSpeech::Interjection => Err(InflectionError::UnsupportedPartOfSpeech(
+                declension.part_of_speech,
+            )),
+        }
+    }
+}
+
+impl WordInflection {
+    fn collect_forms(&self, out: &mut Vec<(String, Declension)>) {
+        let declension = |pos| Declension {
+            decl_type: self.declension_type,
+            ..Declension::partial_default(pos)
+        };
+
+        // the tables don't tell the kind of noun, pronoun, article or numeral apart, the part of
+        // speech they were imported for does
+        let nouns = [
+            (&self.noun, PartOfSpeech::Noun(Noun::Common)),
+            (&self.article, PartOfSpeech::Article(Article::Definite)),
+            (&self.pronoun, PartOfSpeech::Pronoun(Pronoun::Personal)),
+            (&self.quantifier, PartOfSpeech::Quantifier),
+            (&self.numeral, PartOfSpeech::Numeral(Numeral::Cardinal)),
+        ];
+        for (noun, pos) in nouns {
+            let pos = match self.part_of_speech {
+                Some(x) if mem::discriminant(&x) == mem::discriminant(&pos) => x,
+                _ => pos,
+            };
+            if let Some(noun) = noun {
+                collect_forms_noun(noun, &declension(pos), out);
+            }
+        }
+
+        let forms = [
+            (&self.adverb, PartOfSpeech::Adverb),
+            (&self.particle, PartOfSpeech::Particle),
+            (&self.preposition, PartOfSpeech::Preposition),
+        ];
+        for (form, pos) in forms {
+            if let Some(form) = form {
+                collect_forms_form(form, &declension(pos), out);
+            }
+        }
+
+        if let Some(adjective) = &self.adjective {
+            let degrees = [
+                (&adjective.positive, Adjective::Positive),
+                (&adjective.comparative, Adjective::Comparative),
+                (&adjective.superlative, Adjective::Superlative),
+            ];
+            for (degree, adj) in degrees {
+                if let Some(degree) = degree {
+                    collect_forms_noun(degree, &declension(PartOfSpeech::Adjective(adj)), out);
+                }
             }
+        }
+
+        if let Some(verb) = &self.verb {
+            collect_forms_verb(verb, &declension(PartOfSpeech::Verb), out);
+        }
+    }
+}
+
+fn collect_forms_verb(
+    verb: &VerbInflectionTenses,
+    declension: &Declension,
+    out: &mut Vec<(String, Declension)>,
+) {
+    let tenses = [
+        (&verb.present, Tense::Present),
+        (&verb.imperfect, Tense::Imperfect),
+        (&verb.future, Tense::Future),
+        (&verb.aorist, Tense::Aorist),
+        (&verb.aorist_2nd, Tense::Aorist),
+        (&verb.perfect, Tense::Perfect),
+        (&verb.perfect_2nd, Tense::Perfect),
+        (&verb.future_perfect, Tense::FuturePerfect),
+        (&verb.pluperfect, Tense::Pluperfect),
+    ];
+    for (themes, tense) in tenses {
+        let Some(themes) = themes else { continue };
+        for (contractions, theme) in [
+            (&themes.thematic, Theme::Thematic),
+            (&themes.athematic, Theme::Athematic),
+        ] {
+            let Some(contractions) = contractions else {
+                continue;
+            };
+            for (moods, contraction) in [
+                (&contractions.contracted, Contraction::Contracted),
+                (&contractions.uncontracted, Contraction::Uncontracted),
+            ] {
+                let Some(moods) = moods else { continue };
+                let declension = Declension {
+                    tense: Some(tense),
+                    theme: Some(theme),
+                    contraction: Some(contraction),
+                    ..declension.clone()
+                };
+                collect_forms_moods(moods, &declension, out);
+            }
+        }
+    }
+}
+
+fn collect_forms_moods(
+    moods: &VerbInflectionMoods,
+    declension: &Declension,
+    out: &mut Vec<(String, Declension)>,
+) {
+    let finite = [
+        (&moods.indicative, Mood::Indicative),
+        (&moods.subjunctive, Mood::Subjunctive),
+        (&moods.optative, Mood::Optative),
+        (&moods.imperative, Mood::Imperative),
+    ];
+    for (voices, mood) in finite {
+        let Some(voices) = voices else { continue };
+        for (numbers, voice) in [
+            (&voices.active, Voice::Active),
+            (&voices.middle, Voice::Middle),
+            (&voices.passive, Voice::Passive),
+        ] {
+            let Some(numbers) = numbers else { continue };
+            for (persons, number) in [
+                (&numbers.singular, Number::Singular),
+                (&numbers.plural, Number::Plural),
+                (&numbers.dual, Number::Dual),
+            ] {
+                let Some(persons) = persons else { continue };
+                for (form, person) in [
+                    (&persons.first, Person::First),
+                    (&persons.second, Person::Second),
+                    (&persons.third, Person::Third),
+                ] {
+                    let Some(form) = form else { continue };
+                    let declension = Declension {
+                        mood: Some(mood),
+                        voice: Some(voice),
+                        number: Some(number),
+                        person: Some(person),
+                        ..declension.clone()
+                    };
+                    collect_forms_form(form, &declension, out);
+                }
+            }
+        }
+    }
+
+    if let Some(infinitive) = &moods.infinitive {
+        for (form, voice) in [
+            (&infinitive.active, Voice::Active),
+            (&infinitive.middle, Voice::Middle),
+            (&infinitive.passive, Voice::Passive),
+        ] {
+            let Some(form) = form else { continue };
+            let declension = Declension {
+                mood: Some(Mood::Infinitive),
+                voice: Some(voice),
+                ..declension.clone()
+            };
+            collect_forms_form(form, &declension, out);
+        }
+    }
+
+    if let Some(participle) = &moods.participle {
+        for (noun, voice) in [
+            (&participle.active, Voice::Active),
+            (&participle.middle, Voice::Middle),
+            (&participle.passive, Voice::Passive),
+        ] {
+            let Some(noun) = noun else { continue };
+            let declension = Declension {
+                mood: Some(Mood::Participle),
+                voice: Some(voice),
+                ..declension.clone()
+            };
+            collect_forms_noun(noun, &declension, out);
+        }
+    }
+}
+
+fn collect_forms_noun(
+    noun: &NounInflectionGenders,
+    declension: &Declension,
+    out: &mut Vec<(String, Declension)>,
+) {
+    for (numbers, gender) in [
+        (&noun.masculine, Gender::Masculine),
+        (&noun.feminine, Gender::Feminine),
+        (&noun.neuter, Gender::Neuter),
+    ] {
+        let Some(numbers) = numbers else { continue };
+        for (cases, number) in [
+            (&numbers.singular, Number::Singular),
+            (&numbers.dual, Number::Dual),
+            (&numbers.plural, Number::Plural),
+        ] {
+            let Some(cases) = cases else { continue };
+            for (form, case) in [
+                (&cases.nominative, Case::Nominative),
+                (&cases.genitive, Case::Genitive),
+                (&cases.dative, Case::Dative),
+                (&cases.accusative, Case::Accusative),
+                (&cases.vocative, Case::Vocative),
+            ] {
+                let Some(form) = form else { continue };
+                let declension = Declension {
+                    gender: Some(gender),
+                    number: Some(number),
+                    case: Some(case),
+                    ..declension.clone()
+                };
+                collect_forms_form(form, &declension, out);
+            }
+        }
+    }
+}
+
+fn collect_forms_form(
+    form: &[InflectionForm],
+    declension: &Declension,
+    out: &mut Vec<(String, Declension)>,
+) {
+    for x in form {
+        let texts = x.contracted.iter().chain(x.uncontracted.iter().flatten());
+        for text in texts {
+            out.push((text.clone(), declension.clone()));
+        }
+    }
+}
+
+fn field<'a, T>(value: &'a Option<T>, path: &str) -> Result<&'a T, InflectionError> {
+    value
+        .as_ref()
+        .ok_or_else(|| InflectionError::Missing(path.to_string()))
+}
+
+fn required<T>(value: Option<T>, path: &str, field: &'static str) -> Result<T, InflectionError> {
+    value.ok_or_else(|| InflectionError::Unspecified {
+        path: path.to_string(),
+        field,
+    })
+}
+
+fn find_inflection_verb(
+    declension: &Declension,
+    verb: &VerbInflectionTenses,
+) -> Result<Vec<String>, InflectionError> {
+    let tense = required(declension.tense, "verb", "tense")?;
+    let path = format!("verb.{tense}");
+    let tenses = field(
+        match tense {
+            // the codes fold 2nd tenses, so a verb with no 1st one falls back to them
+            Tense::Aorist if verb.aorist.is_none() => &verb.aorist_2nd,
+            Tense::Perfect if verb.perfect.is_none() => &verb.perfect_2nd,
+            Tense::Aorist => &verb.aorist,
+            Tense::Future => &verb.future,
+            Tense::FuturePerfect => &verb.future_perfect,
+            Tense::Imperfect => &verb.imperfect,
+            Tense::Perfect => &verb.perfect,
+            Tense::Pluperfect => &verb.pluperfect,
+            Tense::Present => &verb.present,
         },
-        None => panic!("No mood found for {:?}", declension),
-    };
-    let voice = match declension.voice {
-        Some(voice) => match voice {
-            Voice::Active => mood.active.as_ref().unwrap(),
-            Voice::Middle => mood.middle.as_ref().unwrap(),
-            Voice::Passive => mood.passive.as_ref().unwrap(),
+        &path,
+    )?;
+
+    let theme = declension.theme.unwrap_or(Theme::Thematic);
+    let path = format!("{path}.{theme}");
+    let themes = field(
+        match theme {
+            Theme::Athematic => &tenses.athematic,
+            Theme::Thematic => &tenses.thematic,
         },
-        None => panic!("No voice found for {:?}", declension),
-    };
-    let number = match declension.number {
-        Some(Number::Singular) => voice.singular.as_ref().unwrap(),
-        Some(Number::Plural) => voice.plural.as_ref().unwrap(),
-        Some(Number::Dual) => panic!(
-            "Dual is not a supported number for verb inflection. Found in {:?}",
-            declension
-        ),
-        None => panic!("No number found for {:?}", declension),
-    };
-    let person = match declension.person {
-        Some(person) => match person {
-            Person::First => number.first.as_ref().unwrap(),
-            Person::Second => number.second.as_ref().unwrap(),
-            Person::Third => number.third.as_ref().unwrap(),
+        &path,
+    )?;
+
+    let contraction = declension.contraction.unwrap_or(Contraction::Contracted);
+    let path = format!("{path}.{contraction}");
+    let contractions = field(
+        match contraction {
+            Contraction::Uncontracted => &themes.uncontracted,
+            Contraction::Contracted => &themes.contracted,
         },
-        None => panic!("No person found for {:?}", declension),
+        &path,
+    )?;
+
+    let mood = required(declension.mood, &path, "mood")?;
+    let path = format!("{path}.{mood}");
+    let moods = match mood {
+        Mood::Indicative => field(&contractions.indicative, &path)?,
+        Mood::Imperative => field(&contractions.imperative, &path)?,
+        Mood::Optative => field(&contractions.optative, &path)?,
+        Mood::Subjunctive => field(&contractions.subjunctive, &path)?,
+        Mood::Infinitive => {
+            return find_inflection_verb_infinitive(
+                declension,
+                field(&contractions.infinitive, &path)?,
+                &path,
+            )
+        }
+        Mood::Participle => {
+            return find_inflection_verb_participle(
+                declension,
+                field(&contractions.participle, &path)?,
+                &path,
+            )
+        }
     };
 
-    find_inflection_form(person)
+    let voice = required(declension.voice, &path, "voice")?;
+    let path = format!("{path}.{voice}");
+    let voices = field(
+        match voice {
+            Voice::Active => &moods.active,
+            Voice::Middle => &moods.middle,
+            Voice::Passive => &moods.passive,
+        },
+        &path,
+    )?;
+
+    let number = required(declension.number, &path, "number")?;
+    let path = format!("{path}.{number}");
+    let numbers = field(
+        match number {
+            Number::Singular => &voices.singular,
+            Number::Plural => &voices.plural,
+            Number::Dual => return Err(InflectionError::DualVerb(path)),
+        },
+        &path,
+    )?;
+
+    let person = required(declension.person, &path, "person")?;
+    let path = format!("{path}.{person}");
+    let persons = field(
+        match person {
+            Person::First => &numbers.first,
+            Person::Second => &numbers.second,
+            Person::Third => &numbers.third,
+        },
+        &path,
+    )?;
+
+    Ok(find_inflection_form(persons))
 }
 
-fn find_inflection_adjective(declension: &Declension, adjective: &WordAdjective) -> Vec<String> {
-    let adj = match declension.part_of_speech {
-        PartOfSpeech::Adjective(adj) => match adj {
-            crate::grammar::Adjective::Positive => adjective.positive.as_ref().unwrap(),
-            crate::grammar::Adjective::Comparative => adjective.comparative.as_ref().unwrap(),
-            crate::grammar::Adjective::Superlative => adjective.superlative.as_ref().unwrap(),
+fn find_inflection_adjective(
+    adj: Adjective,
+    declension: &Declension,
+    adjective: &WordAdjective,
+    path: &str,
+) -> Result<Vec<String>, InflectionError> {
+    let path = format!("{path}.{adj}");
+    let degree = field(
+        match adj {
+            Adjective::Positive => &adjective.positive,
+            Adjective::Comparative => &adjective.comparative,
+            Adjective::Superlative => &adjective.superlative,
         },
-        _ => panic!(
-            "pos should be an adjective: {:?}",
-            declension.part_of_speech
-        ),
-    };
-    find_inflection_noun(declension, adj)
+        &path,
+    )?;
+
+    find_inflection_noun(declension, degree, &path)
 }
 
-fn find_inflection_noun(declension: &Declension, noun: &NounInflectionGenders) -> Vec<String> {
-    let gender = match declension.gender {
-        Some(Gender::Masculine) => noun.masculine.as_ref().unwrap(),
-        Some(Gender::Feminine) => noun.feminine.as_ref().unwrap(),
-        Some(Gender::Neuter) => noun.neuter.as_ref().unwrap(),
-        None => return vec![],
+fn find_inflection_noun(
+    declension: &Declension,
+    noun: &NounInflectionGenders,
+    path: &str,
+) -> Result<Vec<String>, InflectionError> {
+    let Some(gender) = declension.gender else {
+        return Ok(vec![]);
     };
-    let number = match declension.number {
-        Some(Number::Singular) => gender.singular.as_ref().unwrap(),
-        Some(Number::Dual) => gender.dual.as_ref().unwrap(),
-        Some(Number::Plural) => gender.plural.as_ref().unwrap(),
-        None => return vec![],
+    let path = format!("{path}.{gender}");
+    let genders = field(
+        match gender {
+            Gender::Masculine => &noun.masculine,
+            Gender::Feminine => &noun.feminine,
+            Gender::Neuter => &noun.neuter,
+        },
+        &path,
+    )?;
+
+    let Some(number) = declension.number else {
+        return Ok(vec![]);
     };
-    let case = match declension.case {
-        Some(Case::Nominative) => number.nominative.as_ref().unwrap(),
-        Some(Case::Genitive) => number.genitive.as_ref().unwrap(),
-        Some(Case::Dative) => number.dative.as_ref().unwrap(),
-        Some(Case::Accusative) => number.accusative.as_ref().unwrap(),
-        Some(Case::Vocative) => number.vocative.as_ref().unwrap(),
-        None => return vec![],
+    let path = format!("{path}.{number}");
+    let numbers = field(
+        match number {
+            Number::Singular => &genders.singular,
+            Number::Dual => &genders.dual,
+            Number::Plural => &genders.plural,
+        },
+        &path,
+    )?;
+
+    let Some(case) = declension.case else {
+        return Ok(vec![]);
     };
-    find_inflection_form(case)
+    let path = format!("{path}.{case}");
+    let cases = field(
+        match case {
+            Case::Nominative => &numbers.nominative,
+            Case::Genitive => &numbers.genitive,
+            Case::Dative => &numbers.dative,
+            Case::Accusative => &numbers.accusative,
+            Case::Vocative => &numbers.vocative,
+        },
+        &path,
+    )?;
+
+    Ok(find_inflection_form(cases))
 }
 
 fn find_inflection_verb_infinitive(
     declension: &Declension,
     infinitive: &VerbInflectionInfinitive,
-) -> Vec<String> {
-    match declension.voice {
-        Some(Voice::Active) => return find_inflection_form(infinitive.active.as_ref().unwrap()),
-        Some(Voice::Middle) => return find_inflection_form(infinitive.middle.as_ref().unwrap()),
-        Some(Voice::Passive) => return find_inflection_form(infinitive.passive.as_ref().unwrap()),
-        None => panic!("No voice found for {:?}", declension),
-    }
+    path: &str,
+) -> Result<Vec<String>, InflectionError> {
+    let voice = required(declension.voice, path, "voice")?;
+    let path = format!("{path}.{voice}");
+    let voices = field(
+        match voice {
+            Voice::Active => &infinitive.active,
+            Voice::Middle => &infinitive.middle,
+            Voice::Passive => &infinitive.passive,
+        },
+        &path,
+    )?;
+
+    Ok(find_inflection_form(voices))
 }
 
 fn find_inflection_verb_participle(
     declension: &Declension,
     participle: &VerbInflectionParticiple,
-) -> Vec<String> {
-    match declension.voice {
-        Some(Voice::Active) => {
-            return find_inflection_noun(declension, participle.active.as_ref().unwrap())
-        }
-        Some(Voice::Middle) => {
-            return find_inflection_noun(declension, participle.middle.as_ref().unwrap())
-        }
-        Some(Voice::Passive) => {
-            return find_inflection_noun(declension, participle.passive.as_ref().unwrap())
-        }
-        None => panic!("No voice found for {:?}", declension),
-    }
+    path: &str,
+) -> Result<Vec<String>, InflectionError> {
+    let voice = required(declension.voice, path, "voice")?;
+    let path = format!("{path}.{voice}");
+    let voices = field(
+        match voice {
+            Voice::Active => &participle.active,
+            Voice::Middle => &participle.middle,
+            Voice::Passive => &participle.passive,
+        },
+        &path,
+    )?;
+
+    find_inflection_noun(declension, voices, &path)
 }
 
 fn find_inflection_form(form: &[InflectionForm]) -> Vec<String> {
-    return form.iter().flat_map(|x| x.contracted.clone()).collect();
+    form.iter().flat_map(|x| x.contracted.clone()).collect()
 }
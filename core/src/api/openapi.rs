@@ -0,0 +1,149 @@
+use actix_web::{get, web, HttpResponse, Responder};
+use utoipa::OpenApi;
+
+use crate::{
+    api::{
+        error::Problem,
+        lexicon::{
+            lexicon_controller,
+            lexicon_model::{
+                BookOccurrences, DefinitionFormOf, InflectionForm, LexiconEntry,
+                LexiconEntryDefinition, LexiconFilter, LexiconFilterInflection, LexiconForm,
+                LexiconOccurrences, LexiconPage, MatchMode, NounInflectionCases,
+                NounInflectionGenders, NounInflectionNumbers, Paradigm, ParadigmRow, ParadigmTable,
+                Syllabification, VerbInflectionContractions, VerbInflectionInfinitive,
+                VerbInflectionMoods, VerbInflectionNumbers, VerbInflectionParticiple,
+                VerbInflectionPersons, VerbInflectionTenses, VerbInflectionThemes,
+                VerbInflectionVoices, WordAdjective, WordInflection, WordSyllable,
+            },
+        },
+        review::{
+            review_controller,
+            review_model::{PendingReview, ReviewReason},
+        },
+        verse::{
+            verse_controller,
+            verse_model::{DeclensionFilter, WordDetail, WordOccurrence},
+            verse_service::{Manifest, ManifestBook, ManifestChapter, ManifestCollection},
+        },
+    },
+    grammar::{
+        parse::ParseStyle, Adjective, Article, Case, Contraction, Declension, DeclensionType,
+        Dialect, Gender, LanguageCode, Mood, Noun, Number, Numeral, PartOfSpeech, Person, Pronoun,
+        Tense, Theme, Verse, Voice, Word,
+    },
+    texts::{Book, Collection},
+    utils::str::syllables::{Position, Quantity},
+};
+
+#[derive(OpenApi)]
+#[openapi(
+    info(title = "syn-text-api"),
+    paths(
+        verse_controller::get_manifest,
+        verse_controller::find_words,
+        verse_controller::get_verse_span,
+        verse_controller::get_chapter,
+        verse_controller::get_verse_range,
+        verse_controller::get_verse,
+        verse_controller::get_word,
+        lexicon_controller::get_lexicon_page,
+        lexicon_controller::get_lexicon,
+        lexicon_controller::analyze_word,
+        lexicon_controller::get_syllables,
+        lexicon_controller::get_paradigm,
+        lexicon_controller::get_occurrences,
+        review_controller::get_reviews,
+        review_controller::accept_review,
+        review_controller::reject_review,
+    ),
+    components(schemas(
+        Problem,
+        Collection,
+        Book,
+        Verse,
+        Word,
+        LanguageCode,
+        Declension,
+        PartOfSpeech,
+        Noun,
+        Pronoun,
+        Article,
+        Adjective,
+        Numeral,
+        Mood,
+        Person,
+        Number,
+        Gender,
+        Case,
+        Voice,
+        Tense,
+        Theme,
+        Contraction,
+        DeclensionType,
+        Dialect,
+        Manifest,
+        ManifestCollection,
+        ManifestBook,
+        ManifestChapter,
+        LexiconEntry,
+        LexiconEntryDefinition,
+        DefinitionFormOf,
+        LexiconFilter,
+        LexiconFilterInflection,
+        LexiconForm,
+        LexiconPage,
+        LexiconOccurrences,
+        BookOccurrences,
+        WordOccurrence,
+        WordDetail,
+        DeclensionFilter,
+        ParseStyle,
+        MatchMode,
+        Paradigm,
+        ParadigmRow,
+        ParadigmTable,
+        Syllabification,
+        WordSyllable,
+        Quantity,
+        Position,
+        WordInflection,
+        WordAdjective,
+        VerbInflectionTenses,
+        VerbInflectionThemes,
+        VerbInflectionContractions,
+        VerbInflectionMoods,
+        VerbInflectionInfinitive,
+        VerbInflectionParticiple,
+        VerbInflectionVoices,
+        VerbInflectionNumbers,
+        VerbInflectionPersons,
+        NounInflectionGenders,
+        NounInflectionNumbers,
+        NounInflectionCases,
+        InflectionForm,
+        PendingReview,
+        ReviewReason,
+    ))
+)]
+pub struct ApiDoc;
+
+#[get("/openapi.json")]
+async fn get_openapi() -> impl Responder {
+    web::Json(ApiDoc::openapi())
+}
+
+/// Redoc page rendering `/openapi.json`, built into the binary so it doesn't depend on the
+/// directory the server runs from.
+const REDOC: &str = include_str!("../public/redoc.html");
+
+#[get("/docs")]
+pub async fn get_docs() -> impl Responder {
+    HttpResponse::Ok()
+        .content_type("text/html; charset=utf-8")
+        .body(REDOC)
+}
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(get_openapi);
+}
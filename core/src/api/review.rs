@@ -0,0 +1,4 @@
+pub mod review_controller;
+pub mod review_model;
+pub mod review_repo;
+pub mod review_service;
@@ -0,0 +1,70 @@
+use crate::{api::error::ApiError, utils::extractors::path_object_id::PathObjectId};
+
+use super::review_service::{Acceptance, ReviewService};
+
+use actix_web::{get, post, web, Responder};
+
+#[utoipa::path(
+    context_path = "/v1/reviews",
+    tag = "reviews",
+    responses((status = 200, body = [PendingReview]))
+)]
+#[get("")]
+async fn get_reviews() -> Result<impl Responder, ApiError> {
+    let reviews = ReviewService::find_many().await?;
+    Ok(web::Json(reviews))
+}
+
+#[utoipa::path(
+    context_path = "/v1/reviews",
+    tag = "reviews",
+    params(("id" = String, Path, description = "Id of the pending review")),
+    responses(
+        (status = 200, body = PendingReview),
+        (status = 404, description = "No pending review found", body = Problem),
+        (status = 409, description = "The word changed since the review was queued", body = Problem),
+    )
+)]
+#[post("/{id}/accept")]
+async fn accept_review(id: PathObjectId) -> Result<impl Responder, ApiError> {
+    match ReviewService::accept(&id.extracted).await? {
+        Acceptance::Accepted(review) => Ok(web::Json(review)),
+        Acceptance::NotFound => {
+            Err(ApiError::not_found("no pending review found").with_param("id"))
+        }
+        Acceptance::Stale {
+            review,
+            current_text,
+        } => Err(ApiError::conflict(format!(
+            "word #{} is now {}, not {}",
+            review.word_index, current_text, review.old_text
+        ))),
+    }
+}
+
+#[utoipa::path(
+    context_path = "/v1/reviews",
+    tag = "reviews",
+    params(("id" = String, Path, description = "Id of the pending review")),
+    responses(
+        (status = 200, body = PendingReview),
+        (status = 404, description = "No pending review found", body = Problem),
+    )
+)]
+#[post("/{id}/reject")]
+async fn reject_review(id: PathObjectId) -> Result<impl Responder, ApiError> {
+    let review = ReviewService::reject(&id.extracted)
+        .await?
+        .ok_or_else(|| ApiError::not_found("no pending review found").with_param("id"))?;
+    Ok(web::Json(review))
+}
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("reviews")
+            .service(get_reviews)
+            .service(accept_review)
+            .service(reject_review)
+            .app_data(web::Data::new(ReviewService::new())),
+    );
+}
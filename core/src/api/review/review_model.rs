@@ -0,0 +1,59 @@
+use mongodb::bson::oid::ObjectId;
+use serde::{Deserialize, Serialize};
+use strum::Display;
+use utoipa::ToSchema;
+
+use crate::{
+    grammar::{parse::ParseStyle, Verse},
+    texts::{Book, Collection},
+};
+
+#[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize, Display, ToSchema)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum ReviewReason {
+    TextChange,
+    VerbNotInflected,
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PendingReview {
+    #[serde(rename = "_id")]
+    #[schema(value_type = Option<String>)]
+    pub id: Option<ObjectId>,
+    pub collection: Collection,
+    pub book: Book,
+    pub chapter_number: u8,
+    pub verse_number: u8,
+    pub word_index: u32,
+    pub old_text: String,
+    pub proposed_text: String,
+    pub reason: ReviewReason,
+    /// Short parse of the word, to tell forms apart while reviewing.
+    #[serde(default)]
+    pub parse: Option<String>,
+}
+
+impl PendingReview {
+    pub fn new(
+        verse: &Verse,
+        word_index: usize,
+        proposed_text: &str,
+        reason: ReviewReason,
+    ) -> Self {
+        Self {
+            id: None,
+            collection: verse.collection,
+            book: verse.book,
+            chapter_number: verse.chapter_number,
+            verse_number: verse.verse_number,
+            word_index: word_index as u32,
+            old_text: verse.words[word_index].text.clone(),
+            proposed_text: proposed_text.to_string(),
+            reason,
+            parse: Some(verse.words[word_index].declension.format(ParseStyle::Short)),
+        }
+    }
+}
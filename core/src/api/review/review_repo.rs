@@ -0,0 +1,104 @@
+use futures::TryStreamExt;
+use mongodb::{
+    bson::{doc, oid::ObjectId, Document},
+    options::{FindOptions, IndexOptions, ReplaceOptions},
+    Collection, IndexModel,
+};
+
+use crate::{
+    error::{MapErrSafe, SafeError},
+    persistence::get_db,
+};
+
+use super::review_model::PendingReview;
+
+pub struct ReviewRepo;
+
+impl ReviewRepo {
+    pub const COLLECTION_NAME: &'static str = "pending_reviews";
+
+    pub async fn find_one(id: &ObjectId) -> Result<Option<PendingReview>, SafeError> {
+        get_collection()
+            .await?
+            .find_one(doc! {"_id": id}, None)
+            .await
+            .map_err_safe()
+    }
+
+    pub async fn find_many() -> Result<Vec<PendingReview>, SafeError> {
+        let options = FindOptions::builder()
+            .sort(doc! {"book": 1, "chapterNumber": 1, "verseNumber": 1, "wordIndex": 1})
+            .build();
+
+        get_collection()
+            .await?
+            .find(None, options)
+            .await
+            .map_err_safe()?
+            .try_collect()
+            .await
+            .map_err_safe()
+    }
+
+    pub async fn upsert_one(review: &PendingReview) -> Result<(), SafeError> {
+        let options = ReplaceOptions::builder().upsert(true).build();
+
+        get_collection()
+            .await?
+            .replace_one(unique_key(review), review, options)
+            .await
+            .map_err_safe()?;
+
+        Ok(())
+    }
+
+    pub async fn delete_one(id: &ObjectId) -> Result<(), SafeError> {
+        get_collection()
+            .await?
+            .delete_one(doc! {"_id": id}, None)
+            .await
+            .map_err_safe()?;
+
+        Ok(())
+    }
+}
+
+fn unique_key(review: &PendingReview) -> Document {
+    doc! {
+        "collection": review.collection.to_string(),
+        "book": review.book.to_string(),
+        "chapterNumber": review.chapter_number as i32,
+        "verseNumber": review.verse_number as i32,
+        "wordIndex": review.word_index as i64,
+        "proposedText": &review.proposed_text,
+    }
+}
+
+async fn get_collection() -> Result<Collection<PendingReview>, SafeError> {
+    Ok(get_db()
+        .await?
+        .collection::<PendingReview>(ReviewRepo::COLLECTION_NAME))
+}
+
+pub async fn configure() -> Result<(), SafeError> {
+    let options = IndexOptions::builder().unique(true).build();
+    let unique_key = IndexModel::builder()
+        .keys(doc! {
+            "collection": 1,
+            "book": 1,
+            "chapterNumber": 1,
+            "verseNumber": 1,
+            "wordIndex": 1,
+            "proposedText": 1,
+        })
+        .options(options)
+        .build();
+
+    get_collection()
+        .await?
+        .create_index(unique_key, None)
+        .await
+        .expect("error creating index!");
+
+    Ok(())
+}
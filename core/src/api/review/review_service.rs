@@ -0,0 +1,100 @@
+use anyhow::Context;
+use mongodb::bson::oid::ObjectId;
+use tracing::info;
+
+use crate::{
+    api::verse::{verse_model::VerseFilter, verse_repo::VerseRepo},
+    error::SafeError,
+};
+
+use super::{
+    review_model::{PendingReview, ReviewReason},
+    review_repo::ReviewRepo,
+};
+
+pub enum Acceptance {
+    Accepted(PendingReview),
+    NotFound,
+    /// The verse word changed since the review was queued.
+    Stale {
+        review: PendingReview,
+        current_text: String,
+    },
+}
+
+pub struct ReviewService {}
+
+impl ReviewService {
+    pub fn new() -> ReviewService {
+        ReviewService {}
+    }
+
+    pub async fn find_many() -> Result<Vec<PendingReview>, SafeError> {
+        ReviewRepo::find_many().await
+    }
+
+    pub async fn submit(review: &PendingReview) -> Result<(), SafeError> {
+        ReviewRepo::upsert_one(review).await?;
+        info!(
+            "queued {} review for word #{} of verse {}:{}:{}: {} -> {}",
+            review.reason,
+            review.word_index,
+            review.book,
+            review.chapter_number,
+            review.verse_number,
+            review.old_text,
+            review.proposed_text
+        );
+        Ok(())
+    }
+
+    /// Applies a pending review and removes it from the queue.
+    ///
+    /// Only text changes touch the verse; accepting any other review keeps the
+    /// word as it is. A review whose old text no longer matches the verse is
+    /// left queued and reported as stale.
+    pub async fn accept(id: &ObjectId) -> Result<Acceptance, SafeError> {
+        let Some(review) = ReviewRepo::find_one(id).await? else {
+            return Ok(Acceptance::NotFound);
+        };
+
+        if review.reason == ReviewReason::TextChange {
+            let mut verse = VerseRepo::find_one(&VerseFilter {
+                collection: Some(review.collection.to_string()),
+                book: Some(review.book.to_string()),
+                chapter_number: Some(review.chapter_number),
+                verse_number: Some(review.verse_number),
+                ..Default::default()
+            })
+            .await?
+            .context("no verse found for review")?;
+
+            let word = verse
+                .words
+                .get_mut(review.word_index as usize)
+                .context("review word index is out of the verse")?;
+            if word.text != review.old_text {
+                return Ok(Acceptance::Stale {
+                    current_text: word.text.clone(),
+                    review,
+                });
+            }
+            word.text = review.proposed_text.clone();
+
+            VerseRepo::update_one(&verse).await?;
+        }
+        ReviewRepo::delete_one(id).await?;
+
+        Ok(Acceptance::Accepted(review))
+    }
+
+    pub async fn reject(id: &ObjectId) -> Result<Option<PendingReview>, SafeError> {
+        let Some(review) = ReviewRepo::find_one(id).await? else {
+            return Ok(None);
+        };
+
+        ReviewRepo::delete_one(id).await?;
+
+        Ok(Some(review))
+    }
+}
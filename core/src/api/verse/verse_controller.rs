@@ -1,7 +1,11 @@
 use crate::{
-    api::verse::verse_model::VerseFilter,
-    error::MapErrActix,
+    api::{
+        error::ApiError,
+        verse::verse_model::{VerseFilter, VerseRange, VerseRef, WordQuery},
+    },
+    grammar::{parse::ParseStyle, Declension, Verse},
     texts::{Book, Collection},
+    utils::extractors::query_nested::QueryNested,
 };
 
 use super::verse_service::VerseService;
@@ -11,7 +15,6 @@ use actix_web::{
     web::{self, Path},
     Responder,
 };
-use anyhow::Context;
 
 use serde::Deserialize;
 
@@ -23,33 +26,297 @@ struct GetVerseParams {
     verse_number: u8,
 }
 
+#[derive(Deserialize, Debug)]
+struct GetWordParams {
+    collection: Collection,
+    book: Book,
+    chapter_number: u8,
+    verse_number: u8,
+    index: usize,
+}
+
+#[derive(Deserialize, Debug)]
+struct GetWordQuery {
+    #[serde(default)]
+    style: ParseStyle,
+}
+
+#[derive(Deserialize, Debug)]
+struct GetChapterParams {
+    collection: Collection,
+    book: Book,
+    chapter_number: u8,
+}
+
+#[derive(Deserialize, Debug)]
+struct GetVerseRangeParams {
+    collection: Collection,
+    book: Book,
+    chapter_number: u8,
+    from_verse: u8,
+    to_verse: u8,
+}
+
+#[derive(Deserialize, Debug)]
+struct GetVerseSpanParams {
+    collection: Collection,
+    book: Book,
+    from_chapter: u8,
+    from_verse: u8,
+    to_chapter: u8,
+    to_verse: u8,
+}
+
+#[utoipa::path(
+    context_path = "/v1/verses",
+    tag = "verses",
+    responses((status = 200, body = Manifest))
+)]
 #[get("/manifest")]
-async fn get_manifest() -> actix_web::Result<impl Responder> {
-    let manifest = VerseService::get_manifest().await.map_err_actix()?;
+async fn get_manifest() -> Result<impl Responder, ApiError> {
+    let manifest = VerseService::get_manifest().await?;
     Ok(web::Json(manifest))
 }
 
+#[utoipa::path(
+    context_path = "/v1/verses",
+    tag = "verses",
+    params(
+        ("collection" = Option<Collection>, Query, description = "Only words of this collection"),
+        ("book" = Option<Book>, Query, description = "Only words of this book"),
+        ("chapter" = Option<u8>, Query, description = "Only words of this chapter"),
+        ("lemma" = Option<String>, Query, description = "Only words resolved to this lemma"),
+        ("declension" = Option<DeclensionFilter>, Query, description = "Any subset of the word's declension", style = DeepObject, explode),
+        ("robinson" = Option<String>, Query, description = "Robinson code such as `V-AAI-3S`, filling the declension fields left unset"),
+        ("morphgnt" = Option<String>, Query, description = "MorphGNT code such as `V- 3AAI-S--`, filling the declension fields left unset"),
+        ("offset" = Option<u32>, Query, description = "Matches to skip"),
+        ("limit" = Option<u32>, Query, description = "Matches to return, 100 by default and 1000 at most"),
+    ),
+    responses(
+        (status = 200, body = [WordOccurrence]),
+        (status = 400, description = "Neither a lemma nor a declension was given, or a code is invalid", body = Problem),
+    )
+)]
+#[get("/words")]
+async fn find_words(params: QueryNested<WordQuery>) -> Result<impl Responder, ApiError> {
+    let QueryNested(mut query) = params;
+    if let Some(code) = &query.robinson {
+        let declension = Declension::from_robinson(code)
+            .map_err(|e| ApiError::bad_request(e.to_string()).with_param("robinson"))?;
+        query.declension.fill(&declension);
+    }
+    if let Some(code) = &query.morphgnt {
+        let declension = Declension::from_morphgnt(code)
+            .map_err(|e| ApiError::bad_request(e.to_string()).with_param("morphgnt"))?;
+        query.declension.fill(&declension);
+    }
+    if query.is_empty() {
+        return Err(
+            ApiError::bad_request("either lemma or declension must be provided")
+                .with_param("declension"),
+        );
+    }
+
+    let words = VerseService::find_words(query).await?;
+
+    Ok(web::Json(words))
+}
+
+#[utoipa::path(
+    context_path = "/v1/verses",
+    tag = "verses",
+    params(
+        ("collection" = Collection, Path, description = "Collection the verses belong to"),
+        ("book" = Book, Path, description = "Book of the collection"),
+        ("from_chapter" = u8, Path, description = "Chapter the range starts in"),
+        ("from_verse" = u8, Path, description = "First verse of the range"),
+        ("to_chapter" = u8, Path, description = "Chapter the range ends in"),
+        ("to_verse" = u8, Path, description = "Last verse of the range"),
+    ),
+    responses(
+        (status = 200, body = [Verse]),
+        (status = 404, description = "No verse found", body = Problem),
+        (status = 422, description = "The range ends before it starts", body = Problem),
+    )
+)]
+#[get("/{collection}/{book}/{from_chapter}:{from_verse}-{to_chapter}:{to_verse}")]
+async fn get_verse_span(params: Path<GetVerseSpanParams>) -> Result<impl Responder, ApiError> {
+    let range = VerseRange {
+        start: VerseRef {
+            chapter_number: params.from_chapter,
+            verse_number: params.from_verse,
+        },
+        end: VerseRef {
+            chapter_number: params.to_chapter,
+            verse_number: params.to_verse,
+        },
+    };
+
+    find_verse_range(params.collection, params.book, range).await
+}
+
+#[utoipa::path(
+    context_path = "/v1/verses",
+    tag = "verses",
+    params(
+        ("collection" = Collection, Path, description = "Collection the verses belong to"),
+        ("book" = Book, Path, description = "Book of the collection"),
+        ("chapter_number" = u8, Path, description = "Chapter number"),
+    ),
+    responses((status = 200, body = [Verse]), (status = 404, description = "No verse found", body = Problem))
+)]
+#[get("/{collection}/{book}/{chapter_number}")]
+async fn get_chapter(params: Path<GetChapterParams>) -> Result<impl Responder, ApiError> {
+    let verses = VerseService::find_many(&VerseFilter {
+        collection: Some(params.collection.to_string()),
+        book: Some(params.book.to_string()),
+        chapter_number: Some(params.chapter_number),
+        ..Default::default()
+    })
+    .await?;
+
+    if verses.is_empty() {
+        return Err(ApiError::not_found("no verse found"));
+    }
+
+    Ok(web::Json(verses))
+}
+
+#[utoipa::path(
+    context_path = "/v1/verses",
+    tag = "verses",
+    params(
+        ("collection" = Collection, Path, description = "Collection the verses belong to"),
+        ("book" = Book, Path, description = "Book of the collection"),
+        ("chapter_number" = u8, Path, description = "Chapter number"),
+        ("from_verse" = u8, Path, description = "First verse of the range"),
+        ("to_verse" = u8, Path, description = "Last verse of the range"),
+    ),
+    responses(
+        (status = 200, body = [Verse]),
+        (status = 404, description = "No verse found", body = Problem),
+        (status = 422, description = "The range ends before it starts", body = Problem),
+    )
+)]
+#[get("/{collection}/{book}/{chapter_number}/{from_verse}-{to_verse}")]
+async fn get_verse_range(params: Path<GetVerseRangeParams>) -> Result<impl Responder, ApiError> {
+    let range = VerseRange {
+        start: VerseRef {
+            chapter_number: params.chapter_number,
+            verse_number: params.from_verse,
+        },
+        end: VerseRef {
+            chapter_number: params.chapter_number,
+            verse_number: params.to_verse,
+        },
+    };
+
+    find_verse_range(params.collection, params.book, range).await
+}
+
+#[utoipa::path(
+    context_path = "/v1/verses",
+    tag = "verses",
+    params(
+        ("collection" = Collection, Path, description = "Collection the verses belong to"),
+        ("book" = Book, Path, description = "Book of the collection"),
+        ("chapter_number" = u8, Path, description = "Chapter number"),
+        ("verse_number" = u8, Path, description = "Verse number"),
+    ),
+    responses((status = 200, body = Verse), (status = 404, description = "No verse found", body = Problem))
+)]
 #[get("/{collection}/{book}/{chapter_number}/{verse_number}")]
-async fn get_verse(params: Path<GetVerseParams>) -> actix_web::Result<impl Responder> {
+async fn get_verse(params: Path<GetVerseParams>) -> Result<impl Responder, ApiError> {
     let verse = VerseService::find_one(&VerseFilter {
         collection: Some(params.collection.to_string()),
         book: Some(params.book.to_string()),
         chapter_number: Some(params.chapter_number),
         verse_number: Some(params.verse_number),
+        ..Default::default()
     })
-    .await
-    .map_err_actix()?
-    .context("no verse found")
-    .map_err_actix()?;
+    .await?
+    .ok_or_else(|| ApiError::not_found("no verse found"))?;
 
     Ok(web::Json(verse))
 }
 
+#[utoipa::path(
+    context_path = "/v1/verses",
+    tag = "verses",
+    params(
+        ("collection" = Collection, Path, description = "Collection the verse belongs to"),
+        ("book" = Book, Path, description = "Book of the collection"),
+        ("chapter_number" = u8, Path, description = "Chapter number"),
+        ("verse_number" = u8, Path, description = "Verse number"),
+        ("index" = usize, Path, description = "Position of the word in the verse, from 0"),
+        ("style" = Option<ParseStyle>, Query, description = "`short` abbreviates the parse, e.g. \"aor act ind 3s\""),
+    ),
+    responses(
+        (status = 200, body = WordDetail),
+        (status = 404, description = "No verse or no word at this index", body = Problem),
+    )
+)]
+#[get("/{collection}/{book}/{chapter_number}/{verse_number}/words/{index}")]
+async fn get_word(
+    params: Path<GetWordParams>,
+    query: QueryNested<GetWordQuery>,
+) -> Result<impl Responder, ApiError> {
+    let filter = VerseFilter {
+        collection: Some(params.collection.to_string()),
+        book: Some(params.book.to_string()),
+        chapter_number: Some(params.chapter_number),
+        verse_number: Some(params.verse_number),
+        ..Default::default()
+    };
+
+    let word = VerseService::find_word(&filter, params.index, query.style)
+        .await?
+        .ok_or_else(|| ApiError::not_found("no word found").with_param("index"))?;
+
+    Ok(web::Json(word))
+}
+
+async fn find_verse_range(
+    collection: Collection,
+    book: Book,
+    range: VerseRange,
+) -> Result<web::Json<Vec<Verse>>, ApiError> {
+    if !range.is_valid() {
+        return Err(ApiError::unprocessable(format!(
+            "Invalid verse range: {}:{} is after {}:{}",
+            range.start.chapter_number,
+            range.start.verse_number,
+            range.end.chapter_number,
+            range.end.verse_number
+        ))
+        .with_param("range"));
+    }
+
+    let verses = VerseService::find_many(&VerseFilter {
+        collection: Some(collection.to_string()),
+        book: Some(book.to_string()),
+        range: Some(range),
+        ..Default::default()
+    })
+    .await?;
+
+    if verses.is_empty() {
+        return Err(ApiError::not_found("no verse found"));
+    }
+
+    Ok(web::Json(verses))
+}
+
 pub fn configure(cfg: &mut web::ServiceConfig) {
     cfg.service(
         web::scope("verses")
-            .service(get_verse)
             .service(get_manifest)
+            .service(find_words)
+            .service(get_verse_span)
+            .service(get_chapter)
+            .service(get_verse_range)
+            .service(get_verse)
+            .service(get_word)
             .app_data(web::Data::new(VerseService::new())),
     );
 }
@@ -1,7 +1,19 @@
-use mongodb::bson::Document;
+use mongodb::bson::{self, doc, Document};
 use nameof::name_of;
+use std::str::FromStr;
 
-use crate::{grammar::Verse, utils::str::camel_case::CamelCase};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::{
+    api::lexicon::lexicon_model::LexiconEntry,
+    grammar::{
+        Case, Contraction, Declension, DeclensionType, Gender, Mood, Number, PartOfSpeech, Person,
+        Tense, Theme, Verse, Voice, Word,
+    },
+    texts::{Book, Collection},
+    utils::str::camel_case::CamelCase,
+};
 
 #[derive(Debug, Default)]
 pub struct VerseFilter {
@@ -9,6 +21,188 @@ pub struct VerseFilter {
     pub book: Option<String>,
     pub chapter_number: Option<u8>,
     pub verse_number: Option<u8>,
+    pub range: Option<VerseRange>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VerseRef {
+    pub chapter_number: u8,
+    pub verse_number: u8,
+}
+
+impl FromStr for VerseRef {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (chapter, verse) = s
+            .split_once(':')
+            .ok_or(format!("expected CHAPTER:VERSE, got '{s}'"))?;
+
+        Ok(VerseRef {
+            chapter_number: chapter
+                .parse()
+                .map_err(|_| format!("invalid chapter '{chapter}'"))?,
+            verse_number: verse
+                .parse()
+                .map_err(|_| format!("invalid verse '{verse}'"))?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VerseRange {
+    pub start: VerseRef,
+    pub end: VerseRef,
+}
+
+impl VerseRange {
+    pub fn is_valid(&self) -> bool {
+        self.start <= self.end
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct WordOccurrence {
+    pub collection: Collection,
+    pub book: Book,
+    pub chapter_number: u8,
+    pub verse_number: u8,
+    pub word_index: u32,
+    pub word: Word,
+    pub context: String,
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Serialize, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct WordDetail {
+    pub collection: Collection,
+    pub book: Book,
+    pub chapter_number: u8,
+    pub verse_number: u8,
+    pub word_index: u32,
+    pub word: Word,
+    pub declension: Declension,
+    pub parse: String,
+    pub robinson: Option<String>,
+    pub morphgnt: Option<String>,
+    pub entry: Option<LexiconEntry>,
+}
+
+/// Any subset of a [`Declension`](crate::grammar::Declension), in the same shape as stored on
+/// verse words.
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DeclensionFilter {
+    pub part_of_speech: Option<PartOfSpeech>,
+    pub mood: Option<Mood>,
+    pub person: Option<Person>,
+    pub number: Option<Number>,
+    pub gender: Option<Gender>,
+    pub case: Option<Case>,
+    pub voice: Option<Voice>,
+    pub tense: Option<Tense>,
+    pub theme: Option<Theme>,
+    pub contraction: Option<Contraction>,
+    pub decl_type: Option<DeclensionType>,
+}
+
+impl DeclensionFilter {
+    /// Sets the fields still unset from `declension`.
+    pub fn fill(&mut self, declension: &Declension) {
+        self.part_of_speech = self.part_of_speech.or(Some(declension.part_of_speech));
+        self.mood = self.mood.or(declension.mood);
+        self.person = self.person.or(declension.person);
+        self.number = self.number.or(declension.number);
+        self.gender = self.gender.or(declension.gender);
+        self.case = self.case.or(declension.case);
+        self.voice = self.voice.or(declension.voice);
+        self.tense = self.tense.or(declension.tense);
+        self.theme = self.theme.or(declension.theme);
+        self.contraction = self.contraction.or(declension.contraction);
+        self.decl_type = self.decl_type.or(declension.decl_type);
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct WordQuery {
+    pub collection: Option<Collection>,
+    pub book: Option<Book>,
+    pub chapter: Option<u8>,
+    pub lemma: Option<String>,
+    #[serde(default)]
+    pub declension: DeclensionFilter,
+    pub robinson: Option<String>,
+    pub morphgnt: Option<String>,
+    pub offset: Option<u32>,
+    pub limit: Option<u32>,
+}
+
+impl WordQuery {
+    pub const DEFAULT_LIMIT: u32 = 100;
+    pub const MAX_LIMIT: u32 = 1000;
+
+    pub fn is_empty(&self) -> bool {
+        self.lemma.is_none() && self.word_match("").is_empty()
+    }
+
+    /// Conditions on a single word, keyed under `prefix` (`""` inside `$elemMatch`,
+    /// `"words."` once the words are unwound).
+    pub fn word_match(&self, prefix: &str) -> Document {
+        let mut doc = Document::new();
+
+        if let Some(lemma) = &self.lemma {
+            doc.insert(format!("{prefix}lemma"), lemma);
+        }
+        if let Ok(declension) = bson::to_document(&self.declension) {
+            for (key, value) in declension {
+                doc.insert(format!("{prefix}declension.{key}"), value);
+            }
+        }
+
+        doc
+    }
+
+    pub fn scope(&self) -> VerseFilter {
+        VerseFilter {
+            collection: self.collection.map(|x| x.to_string()),
+            book: self.book.map(|x| x.to_string()),
+            chapter_number: self.chapter,
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChapterKey {
+    pub collection: Collection,
+    pub book: Book,
+    pub chapter_number: u8,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ChapterCount {
+    #[serde(rename = "_id")]
+    pub chapter: ChapterKey,
+    pub verses: i32,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BookKey {
+    pub collection: Collection,
+    pub book: Book,
+}
+
+/// Words matching a [`WordQuery`] in one book.
+#[derive(Debug, Deserialize)]
+pub struct BookWordCount {
+    #[serde(rename = "_id")]
+    pub book: BookKey,
+    pub words: i32,
 }
 
 impl From<&Verse> for VerseFilter {
@@ -18,6 +212,7 @@ impl From<&Verse> for VerseFilter {
             book: Some(verse.book.to_string()),
             chapter_number: Some(verse.chapter_number),
             verse_number: Some(verse.verse_number),
+            range: None,
         }
     }
 }
@@ -38,7 +233,42 @@ impl From<&VerseFilter> for Document {
         if let Some(verse_number) = value.verse_number {
             doc.insert(name_of!(verse_number).camel_case(), verse_number as i32);
         }
+        if let Some(range) = &value.range {
+            doc.extend(Document::from(range));
+        }
 
         doc
     }
 }
+
+impl From<&VerseRange> for Document {
+    fn from(range: &VerseRange) -> Self {
+        let chapter_key = name_of!(chapter_number in Verse).camel_case();
+        let verse_key = name_of!(verse_number in Verse).camel_case();
+        let start = range.start;
+        let end = range.end;
+
+        if start.chapter_number == end.chapter_number {
+            return doc! {
+                &chapter_key: start.chapter_number as i32,
+                &verse_key: {"$gte": start.verse_number as i32, "$lte": end.verse_number as i32},
+            };
+        }
+
+        doc! {
+            "$or": [
+                {
+                    &chapter_key: start.chapter_number as i32,
+                    &verse_key: {"$gte": start.verse_number as i32},
+                },
+                {
+                    &chapter_key: {"$gt": start.chapter_number as i32, "$lt": end.chapter_number as i32},
+                },
+                {
+                    &chapter_key: end.chapter_number as i32,
+                    &verse_key: {"$lte": end.verse_number as i32},
+                },
+            ]
+        }
+    }
+}
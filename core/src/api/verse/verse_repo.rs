@@ -1,22 +1,26 @@
+use futures::TryStreamExt;
 use mongodb::{
-    bson::{doc},
-    options::IndexOptions,
+    bson::{self, doc, Document},
+    options::{FindOptions, IndexOptions, ReplaceOptions},
     Collection, IndexModel,
 };
-
+use strum::IntoEnumIterator;
 
 use crate::{
     error::{MapErrSafe, SafeError},
     grammar::Verse,
     persistence::get_db,
+    redis,
+    texts::Book,
 };
 
-use super::verse_model::VerseFilter;
+use super::verse_model::{BookWordCount, ChapterCount, VerseFilter, WordOccurrence, WordQuery};
 
 pub struct VerseRepo;
 
 impl VerseRepo {
     pub const COLLECTION_NAME: &'static str = "verses";
+    pub const MANIFEST_CACHE_KEY: &'static str = "verses:manifest";
 
     pub async fn find_one(filter: &VerseFilter) -> Result<Option<Verse>, SafeError> {
         get_collection()
@@ -26,17 +30,180 @@ impl VerseRepo {
             .map_err_safe()
     }
 
-    pub async fn update_one(update: &Verse) -> Result<(), SafeError> {
+    pub async fn find_many(filter: &VerseFilter) -> Result<Vec<Verse>, SafeError> {
+        let options = FindOptions::builder()
+            .sort(doc! {"chapterNumber": 1, "verseNumber": 1})
+            .build();
+
         get_collection()
             .await?
-            .replace_one((&VerseFilter::from(update)).into(), update, None)
+            .find(Some(filter.into()), options)
+            .await
+            .map_err_safe()?
+            .try_collect()
+            .await
+            .map_err_safe()
+    }
+
+    pub async fn count_chapters() -> Result<Vec<ChapterCount>, SafeError> {
+        let pipeline = [doc! {
+            "$group": {
+                "_id": {
+                    "collection": "$collection",
+                    "book": "$book",
+                    "chapterNumber": "$chapterNumber",
+                },
+                "verses": {"$sum": 1},
+            }
+        }];
+
+        let docs: Vec<_> = get_collection()
+            .await?
+            .aggregate(pipeline, None)
+            .await
+            .map_err_safe()?
+            .try_collect()
+            .await
+            .map_err_safe()?;
+
+        docs.into_iter()
+            .map(|doc| bson::from_document::<ChapterCount>(doc).map_err(|e| e.into()))
+            .collect()
+    }
+
+    pub async fn find_words(query: &WordQuery) -> Result<Vec<WordOccurrence>, SafeError> {
+        let mut pipeline = vec![
+            doc! {"$match": verses_with_words(query)},
+            doc! {
+                "$addFields": {
+                    "bookIndex": {"$indexOfArray": [canonical_books(), "$book"]},
+                    "context": {
+                        "$reduce": {
+                            "input": "$words.text",
+                            "initialValue": "",
+                            "in": {
+                                "$concat": [
+                                    "$$value",
+                                    {"$cond": [{"$eq": ["$$value", ""]}, "", " "]},
+                                    "$$this",
+                                ]
+                            },
+                        }
+                    }
+                }
+            },
+            doc! {"$unwind": {"path": "$words", "includeArrayIndex": "wordIndex"}},
+            doc! {"$match": query.word_match("words.")},
+            doc! {
+                "$sort": {
+                    "collection": 1,
+                    "bookIndex": 1,
+                    "chapterNumber": 1,
+                    "verseNumber": 1,
+                    "wordIndex": 1,
+                }
+            },
+        ];
+        if let Some(offset) = query.offset {
+            pipeline.push(doc! {"$skip": offset as i64});
+        }
+        if let Some(limit) = query.limit {
+            pipeline.push(doc! {"$limit": limit as i64});
+        }
+        pipeline.push(doc! {
+            "$project": {
+                "_id": 0,
+                "collection": 1,
+                "book": 1,
+                "chapterNumber": 1,
+                "verseNumber": 1,
+                "wordIndex": 1,
+                "word": "$words",
+                "context": 1,
+            }
+        });
+
+        let docs: Vec<_> = get_collection()
+            .await?
+            .aggregate(pipeline, None)
+            .await
+            .map_err_safe()?
+            .try_collect()
             .await
             .map_err_safe()?;
 
+        docs.into_iter()
+            .map(|doc| bson::from_document::<WordOccurrence>(doc).map_err(|e| e.into()))
+            .collect()
+    }
+
+    /// Words matching `query` in each book, in canonical order. Its offset and limit are
+    /// ignored.
+    pub async fn count_words(query: &WordQuery) -> Result<Vec<BookWordCount>, SafeError> {
+        let pipeline = [
+            doc! {"$match": verses_with_words(query)},
+            doc! {"$unwind": "$words"},
+            doc! {"$match": query.word_match("words.")},
+            doc! {
+                "$group": {
+                    "_id": {"collection": "$collection", "book": "$book"},
+                    "words": {"$sum": 1},
+                }
+            },
+            doc! {"$addFields": {"bookIndex": {"$indexOfArray": [canonical_books(), "$_id.book"]}}},
+            doc! {"$sort": {"_id.collection": 1, "bookIndex": 1}},
+        ];
+
+        let docs: Vec<_> = get_collection()
+            .await?
+            .aggregate(pipeline, None)
+            .await
+            .map_err_safe()?
+            .try_collect()
+            .await
+            .map_err_safe()?;
+
+        docs.into_iter()
+            .map(|doc| bson::from_document::<BookWordCount>(doc).map_err(|e| e.into()))
+            .collect()
+    }
+
+    pub async fn update_one(update: &Verse) -> Result<(), SafeError> {
+        let options = ReplaceOptions::builder().upsert(true).build();
+
+        let result = get_collection()
+            .await?
+            .replace_one((&VerseFilter::from(update)).into(), update, options)
+            .await
+            .map_err_safe()?;
+
+        // the manifest only counts verses, so only a new one changes it
+        if result.upserted_id.is_some() {
+            invalidate_manifest().await?;
+        }
         Ok(())
     }
 }
 
+/// Verses in the scope of `query` with at least one matching word.
+fn verses_with_words(query: &WordQuery) -> Document {
+    let mut scope = Document::from(&query.scope());
+    let word_match = query.word_match("");
+    if !word_match.is_empty() {
+        scope.insert("words", doc! {"$elemMatch": word_match});
+    }
+    scope
+}
+
+/// Book names in canonical order, for books to sort by rather than by name.
+fn canonical_books() -> Vec<String> {
+    Book::iter().map(|x| x.to_string()).collect()
+}
+
+async fn invalidate_manifest() -> Result<(), SafeError> {
+    redis::del(VerseRepo::MANIFEST_CACHE_KEY).await
+}
+
 async fn get_collection() -> Result<Collection<Verse>, SafeError> {
     Ok(get_db()
         .await?
@@ -50,9 +217,11 @@ pub async fn configure() -> Result<(), SafeError> {
         .options(options)
         .build();
 
+    let word_lemma = IndexModel::builder().keys(doc! {"words.lemma": 1}).build();
+
     get_collection()
         .await?
-        .create_index(unique_key, None)
+        .create_indexes([unique_key, word_lemma], None)
         .await
         .expect("error creating index!");
 
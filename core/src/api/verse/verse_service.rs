@@ -1,28 +1,43 @@
-use serde::Serialize;
+use std::collections::BTreeMap;
 
-use crate::{error::SafeError, grammar::Verse};
+use serde::{Deserialize, Serialize};
+use tracing::debug;
+use utoipa::ToSchema;
 
-use super::{verse_model::VerseFilter, verse_repo::VerseRepo};
+use crate::{
+    api::lexicon::{
+        lexicon_model::{LexiconFilter, LexiconFilterInflection},
+        lexicon_repo::LexiconRepo,
+    },
+    error::SafeError,
+    grammar::{parse::ParseStyle, Verse},
+    redis,
+};
 
-#[derive(Serialize)]
+use super::{
+    verse_model::{VerseFilter, WordDetail, WordOccurrence, WordQuery},
+    verse_repo::VerseRepo,
+};
+
+#[derive(Serialize, Deserialize, ToSchema)]
 pub struct ManifestChapter {
     pub number: i32,
     pub verses: i32,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, Deserialize, ToSchema)]
 pub struct ManifestBook {
     pub name: String,
     pub chapters: Vec<ManifestChapter>,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, Deserialize, ToSchema)]
 pub struct ManifestCollection {
     pub name: String,
     pub books: Vec<ManifestBook>,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, Deserialize, ToSchema)]
 pub struct Manifest {
     pub collections: Vec<ManifestCollection>,
 }
@@ -38,128 +53,122 @@ impl VerseService {
         VerseRepo::find_one(filter).await
     }
 
-    pub async fn get_manifest() -> Result<Manifest, SafeError> {
-        Ok(Manifest {
-            collections: Vec::<ManifestCollection>::from([ManifestCollection {
-                name: "new_testament".to_owned(),
-                books: Vec::<ManifestBook>::from([ManifestBook {
-                    name: "matthew".to_owned(),
-                    chapters: Vec::<ManifestChapter>::from([
-                        ManifestChapter {
-                            number: 1,
-                            verses: 25,
-                        },
-                        ManifestChapter {
-                            number: 2,
-                            verses: 23,
-                        },
-                        ManifestChapter {
-                            number: 3,
-                            verses: 17,
-                        },
-                        ManifestChapter {
-                            number: 4,
-                            verses: 25,
-                        },
-                        ManifestChapter {
-                            number: 5,
-                            verses: 48,
-                        },
-                        ManifestChapter {
-                            number: 6,
-                            verses: 34,
-                        },
-                        ManifestChapter {
-                            number: 7,
-                            verses: 29,
-                        },
-                        ManifestChapter {
-                            number: 8,
-                            verses: 34,
-                        },
-                        ManifestChapter {
-                            number: 9,
-                            verses: 38,
-                        },
-                        ManifestChapter {
-                            number: 10,
-                            verses: 42,
-                        },
-                        ManifestChapter {
-                            number: 11,
-                            verses: 30,
-                        },
-                        ManifestChapter {
-                            number: 12,
-                            verses: 50,
-                        },
-                        ManifestChapter {
-                            number: 13,
-                            verses: 58,
-                        },
-                        ManifestChapter {
-                            number: 14,
-                            verses: 36,
-                        },
-                        ManifestChapter {
-                            number: 15,
-                            verses: 39,
-                        },
-                        ManifestChapter {
-                            number: 16,
-                            verses: 28,
-                        },
-                        ManifestChapter {
-                            number: 17,
-                            verses: 27,
-                        },
-                        ManifestChapter {
-                            number: 18,
-                            verses: 35,
-                        },
-                        ManifestChapter {
-                            number: 19,
-                            verses: 30,
-                        },
-                        ManifestChapter {
-                            number: 20,
-                            verses: 34,
-                        },
-                        ManifestChapter {
-                            number: 21,
-                            verses: 46,
-                        },
-                        ManifestChapter {
-                            number: 22,
-                            verses: 46,
-                        },
-                        ManifestChapter {
-                            number: 23,
-                            verses: 39,
-                        },
-                        ManifestChapter {
-                            number: 24,
-                            verses: 51,
-                        },
-                        ManifestChapter {
-                            number: 25,
-                            verses: 46,
-                        },
-                        ManifestChapter {
-                            number: 26,
-                            verses: 75,
-                        },
-                        ManifestChapter {
-                            number: 27,
-                            verses: 66,
-                        },
-                        ManifestChapter {
-                            number: 28,
-                            verses: 20,
-                        },
-                    ]),
-                }]),
-            }]),
+    pub async fn find_many(filter: &VerseFilter) -> Result<Vec<Verse>, SafeError> {
+        VerseRepo::find_many(filter).await
+    }
+
+    pub async fn find_words(mut query: WordQuery) -> Result<Vec<WordOccurrence>, SafeError> {
+        query.limit = Some(
+            query
+                .limit
+                .unwrap_or(WordQuery::DEFAULT_LIMIT)
+                .clamp(1, WordQuery::MAX_LIMIT),
+        );
+
+        VerseRepo::find_words(&query).await
+    }
+
+    pub async fn find_word(
+        filter: &VerseFilter,
+        index: usize,
+        style: ParseStyle,
+    ) -> Result<Option<WordDetail>, SafeError> {
+        let Some(verse) = VerseRepo::find_one(filter).await? else {
+            return Ok(None);
+        };
+        let Some(word) = verse.words.get(index).cloned() else {
+            return Ok(None);
+        };
+
+        // a parse too partial to look up the inflection falls back to the lemma, as a miss does
+        let by_inflection = LexiconRepo::find_one(LexiconFilter {
+            inflection: Some(LexiconFilterInflection {
+                word: word.text.clone(),
+                declension: word.declension.clone(),
+            }),
+            ..Default::default()
         })
+        .await
+        .unwrap_or_else(|e| {
+            debug!("could not find {} by its inflection: {e}", word.text);
+            None
+        });
+        let entry = match by_inflection {
+            Some(entry) => Some(entry),
+            None => match &word.lemma {
+                Some(lemma) => {
+                    LexiconRepo::find_one(LexiconFilter {
+                        lemma: Some(lemma.clone()),
+                        ..Default::default()
+                    })
+                    .await?
+                }
+                None => None,
+            },
+        };
+
+        Ok(Some(WordDetail {
+            collection: verse.collection,
+            book: verse.book,
+            chapter_number: verse.chapter_number,
+            verse_number: verse.verse_number,
+            word_index: index as u32,
+            declension: word.declension.clone(),
+            parse: word.declension.format(style),
+            robinson: word.declension.to_robinson().ok(),
+            morphgnt: word.declension.to_morphgnt().ok(),
+            word,
+            entry,
+        }))
     }
+
+    pub async fn get_manifest() -> Result<Manifest, SafeError> {
+        if let Some(cached) = redis::get(VerseRepo::MANIFEST_CACHE_KEY).await? {
+            return Ok(serde_json::from_str(&cached)?);
+        }
+
+        let manifest = build_manifest().await?;
+        redis::set(
+            VerseRepo::MANIFEST_CACHE_KEY,
+            &serde_json::to_string(&manifest)?,
+        )
+        .await?;
+
+        Ok(manifest)
+    }
+}
+
+async fn build_manifest() -> Result<Manifest, SafeError> {
+    let mut collections = BTreeMap::new();
+    for count in VerseRepo::count_chapters().await? {
+        let key = count.chapter;
+        collections
+            .entry(key.collection)
+            .or_insert_with(BTreeMap::new)
+            .entry(key.book)
+            .or_insert_with(BTreeMap::new)
+            .insert(key.chapter_number, count.verses);
+    }
+
+    Ok(Manifest {
+        collections: collections
+            .into_iter()
+            .map(|(collection, books)| ManifestCollection {
+                name: collection.to_string(),
+                books: books
+                    .into_iter()
+                    .map(|(book, chapters)| ManifestBook {
+                        name: book.to_string(),
+                        chapters: chapters
+                            .into_iter()
+                            .map(|(number, verses)| ManifestChapter {
+                                number: number as i32,
+                                verses,
+                            })
+                            .collect(),
+                    })
+                    .collect(),
+            })
+            .collect(),
+    })
 }
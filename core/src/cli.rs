@@ -0,0 +1,197 @@
+use std::path::PathBuf;
+
+use clap::{Args, Parser, Subcommand};
+use mongodb::bson::oid::ObjectId;
+use tracing::info;
+
+use crate::{
+    api::{
+        self,
+        lexicon::{lexicon_repo, lexicon_service::LexiconService},
+        review::review_service::{Acceptance, ReviewService},
+        verse::verse_model::{VerseRange, VerseRef},
+    },
+    error::SafeError,
+    export,
+    scrappers::{self, wiki::ImportMode},
+    texts::{Book, Collection},
+    validation,
+};
+
+#[derive(Parser, Debug)]
+#[command(about = "Greek text API and importers")]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Start the API server (default)
+    Serve,
+    /// Import data into the database
+    #[command(subcommand)]
+    Import(ImportCommand),
+    /// Export the database to JSON files
+    Export(ExportArgs),
+    /// Decide on the changes queued by a non-interactive import
+    #[command(subcommand)]
+    Review(ReviewCommand),
+    /// Rebuild the lexicon search keys and the form index used by `/v1/lexicon/analyze`
+    Index,
+    /// Regenerate the noun and verb paradigms of the lexicon and report where they disagree
+    /// with the stored tables
+    Validate(ValidateArgs),
+}
+
+#[derive(Subcommand, Debug)]
+pub enum ImportCommand {
+    /// Import verses from Abarim
+    Abarim(AbarimArgs),
+    /// Resolve the words of a verse range against Wiktionary and fill the lexicon
+    Lexicon(LexiconArgs),
+    /// Import from Katabiblon
+    Katabiblon,
+    /// Import verses, parses and lemmas from local MorphGNT files
+    Morphgnt(MorphgntArgs),
+}
+
+#[derive(Args, Debug)]
+pub struct AbarimArgs {
+    /// Only import this book, e.g. `matthew`
+    #[arg(long)]
+    pub book: Option<Book>,
+    /// Only import this chapter
+    #[arg(long, requires = "book")]
+    pub chapter: Option<u8>,
+}
+
+#[derive(Args, Debug)]
+pub struct MorphgntArgs {
+    /// Directory holding the MorphGNT `.txt` files
+    #[arg(long)]
+    pub dir: PathBuf,
+    /// Only import this book, e.g. `matthew`
+    #[arg(long)]
+    pub book: Option<Book>,
+}
+
+#[derive(Args, Debug)]
+pub struct LexiconArgs {
+    #[arg(long, default_value = "new_testament")]
+    pub collection: Collection,
+    #[arg(long)]
+    pub book: Book,
+    /// First verse to import, as `CHAPTER:VERSE`, defaults to the start of the book
+    #[arg(long)]
+    pub from: Option<VerseRef>,
+    /// Last verse to import, as `CHAPTER:VERSE`, defaults to the end of the book
+    #[arg(long)]
+    pub to: Option<VerseRef>,
+    /// Ignore the saved progress of this range and import it from the start
+    #[arg(long)]
+    pub restart: bool,
+    /// Queue the changes that need a human decision in `pending_reviews`
+    /// instead of prompting for them
+    #[arg(long)]
+    pub non_interactive: bool,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum ReviewCommand {
+    /// List the pending reviews
+    List,
+    /// Apply the proposed change of a review
+    Accept { id: String },
+    /// Discard a review
+    Reject { id: String },
+}
+
+#[derive(Args, Debug)]
+pub struct ExportArgs {
+    /// Directory the `verses.json` and `lexicon.json` files are written to
+    #[arg(long, default_value = ".")]
+    pub output: PathBuf,
+}
+
+#[derive(Args, Debug)]
+pub struct ValidateArgs {
+    /// File the report of mismatched cells is written to
+    #[arg(long, default_value = "validation.json")]
+    pub output: PathBuf,
+}
+
+pub async fn run(cli: Cli) -> Result<(), SafeError> {
+    match cli.command.unwrap_or(Command::Serve) {
+        Command::Serve => api::init().await,
+        Command::Import(ImportCommand::Abarim(args)) => {
+            scrappers::abarim::import(args.book, args.chapter).await
+        }
+        Command::Import(ImportCommand::Lexicon(args)) => {
+            let mode = if args.non_interactive {
+                ImportMode::Queue
+            } else {
+                ImportMode::Interactive
+            };
+            let range = VerseRange {
+                start: args.from.unwrap_or(VerseRef {
+                    chapter_number: 1,
+                    verse_number: 1,
+                }),
+                end: args.to.unwrap_or(VerseRef {
+                    chapter_number: args.book.chapter_count(),
+                    verse_number: u8::MAX,
+                }),
+            };
+            if !range.is_valid() {
+                return Err("--from must not be after --to".into());
+            }
+            scrappers::wiki::import(args.collection, args.book, range, mode, args.restart).await
+        }
+        Command::Import(ImportCommand::Katabiblon) => scrappers::katabiblon::import().await,
+        Command::Import(ImportCommand::Morphgnt(args)) => {
+            scrappers::morphgnt::import(&args.dir, args.book).await
+        }
+        Command::Index => {
+            lexicon_repo::configure().await?;
+            let count = LexiconService::rebuild_search_index().await?;
+            info!("indexed the forms of {count} lexicon entries");
+            Ok(())
+        }
+        Command::Export(args) => {
+            export::export_verses(&args.output.join("verses.json")).await?;
+            export::export_lexicon(&args.output.join("lexicon.json")).await
+        }
+        Command::Validate(args) => validation::validate_paradigms(&args.output).await,
+        Command::Review(ReviewCommand::List) => {
+            for review in ReviewService::find_many().await? {
+                println!("{}", serde_json::to_string(&review)?);
+            }
+            Ok(())
+        }
+        Command::Review(ReviewCommand::Accept { id }) => {
+            match ReviewService::accept(&ObjectId::parse_str(&id)?).await? {
+                Acceptance::Accepted(review) => {
+                    info!("accepted {} -> {}", review.old_text, review.proposed_text);
+                    Ok(())
+                }
+                Acceptance::NotFound => Err(format!("no pending review {id}").into()),
+                Acceptance::Stale {
+                    review,
+                    current_text,
+                } => Err(format!(
+                    "review {id} is stale: word is now {}, not {}",
+                    current_text, review.old_text
+                )
+                .into()),
+            }
+        }
+        Command::Review(ReviewCommand::Reject { id }) => {
+            let review = ReviewService::reject(&ObjectId::parse_str(&id)?)
+                .await?
+                .ok_or_else(|| format!("no pending review {id}"))?;
+            info!("rejected {} -> {}", review.old_text, review.proposed_text);
+            Ok(())
+        }
+    }
+}
@@ -16,6 +16,7 @@ pub enum EnvVar {
     MongoUri,
     RedisUri,
     RedisKeyPrefix,
+    ParseLabels,
 }
 
 impl EnvVar {
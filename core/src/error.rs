@@ -15,25 +15,13 @@ pub trait IntoErr<T> {
 
 impl IntoErr<io::Error> for mongodb::error::Error {
     fn into_err(self) -> io::Error {
-        io::Error::new(io::ErrorKind::Other, self)
+        io::Error::other(self)
     }
 }
 
 impl IntoErr<io::Error> for SafeError {
     fn into_err(self) -> io::Error {
-        io::Error::new(io::ErrorKind::Other, self.to_string())
-    }
-}
-
-impl IntoErr<actix_web::Error> for SafeError {
-    fn into_err(self) -> actix_web::Error {
-        actix_web::error::ErrorInternalServerError(self.to_string())
-    }
-}
-
-impl IntoErr<actix_web::Error> for anyhow::Error {
-    fn into_err(self) -> actix_web::Error {
-        actix_web::error::ErrorInternalServerError(self.to_string())
+        io::Error::other(self.to_string())
     }
 }
 
@@ -80,6 +68,7 @@ where
     }
 }
 
+#[allow(dead_code)]
 pub trait MapErrIo<TRes> {
     fn map_err_io(self) -> Result<TRes, io::Error>
     where
@@ -92,22 +81,6 @@ impl<TRes> MapErrIo<TRes> for Result<TRes, SafeError> {
     }
 }
 
-pub trait MapErrActix<TRes> {
-    fn map_err_actix(self) -> Result<TRes, actix_web::Error>;
-}
-
-impl<TRes> MapErrActix<TRes> for Result<TRes, SafeError> {
-    fn map_err_actix(self) -> Result<TRes, actix_web::Error> {
-        self.map_into_err()
-    }
-}
-
-impl<TRes> MapErrActix<TRes> for Result<TRes, anyhow::Error> {
-    fn map_err_actix(self) -> Result<TRes, actix_web::Error> {
-        self.map_into_err()
-    }
-}
-
 pub trait MapErrSafe<TRes> {
     fn map_err_safe(self) -> Result<TRes, SafeError>;
 }
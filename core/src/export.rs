@@ -0,0 +1,38 @@
+use std::{fs::File, io::BufWriter, path::Path};
+
+use serde::Serialize;
+use tracing::info;
+
+use crate::{
+    api::{
+        lexicon::{lexicon_model::LexiconFilter, lexicon_repo::LexiconRepo},
+        verse::{verse_model::VerseFilter, verse_repo::VerseRepo},
+    },
+    error::SafeError,
+};
+
+pub async fn export_verses(path: &Path) -> Result<(), SafeError> {
+    let verses = VerseRepo::find_many(&VerseFilter::default()).await?;
+    write_json(path, &verses)?;
+
+    info!("{} verses exported to {}", verses.len(), path.display());
+    Ok(())
+}
+
+pub async fn export_lexicon(path: &Path) -> Result<(), SafeError> {
+    let entries = LexiconRepo::find_many(LexiconFilter::default()).await?;
+    write_json(path, &entries)?;
+
+    info!(
+        "{} lexicon entries exported to {}",
+        entries.len(),
+        path.display()
+    );
+    Ok(())
+}
+
+pub fn write_json<T: Serialize>(path: &Path, value: &T) -> Result<(), SafeError> {
+    let file = File::create(path)?;
+    serde_json::to_writer_pretty(BufWriter::new(file), value)?;
+    Ok(())
+}
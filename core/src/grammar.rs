@@ -1,10 +1,20 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, fmt};
 
 use serde::{Deserialize, Serialize};
 use strum::Display;
+use utoipa::{
+    openapi::{ObjectBuilder, OneOfBuilder, Ref, RefOr, Schema, SchemaType},
+    ToSchema,
+};
 
 use crate::texts::{Book, Collection};
 
+use self::parse::ParseStyle;
+
+pub mod codes;
+pub mod parse;
+mod validate;
+
 #[derive(
     Debug, PartialEq, Clone, Copy, Serialize, Deserialize, Display, Hash, Eq, PartialOrd, Ord,
 )]
@@ -30,8 +40,43 @@ pub enum PartOfSpeech {
     Adjective(Adjective),
 }
 
+impl<'s> ToSchema<'s> for PartOfSpeech {
+    fn schema() -> (&'s str, RefOr<Schema>) {
+        let simple = ObjectBuilder::new()
+            .schema_type(SchemaType::String)
+            .enum_values(Some([
+                "verb",
+                "adverb",
+                "preposition",
+                "particle",
+                "interjection",
+                "quantifier",
+            ]));
+        let schema = OneOfBuilder::new()
+            .item(simple)
+            .item(Ref::from_schema_name("Numeral"))
+            .item(Ref::from_schema_name("Noun"))
+            .item(Ref::from_schema_name("Pronoun"))
+            .item(Ref::from_schema_name("Article"))
+            .item(Ref::from_schema_name("Adjective"));
+
+        ("PartOfSpeech", schema.into())
+    }
+}
+
 #[derive(
-    Debug, PartialEq, Clone, Copy, Serialize, Deserialize, Display, Hash, Eq, PartialOrd, Ord,
+    Debug,
+    PartialEq,
+    Clone,
+    Copy,
+    Serialize,
+    Deserialize,
+    Display,
+    Hash,
+    Eq,
+    PartialOrd,
+    Ord,
+    ToSchema,
 )]
 #[strum(serialize_all = "snake_case")]
 #[serde(rename_all = "snake_case")]
@@ -43,7 +88,18 @@ pub enum Noun {
 }
 
 #[derive(
-    Debug, PartialEq, Clone, Copy, Serialize, Deserialize, Display, Hash, Eq, PartialOrd, Ord,
+    Debug,
+    PartialEq,
+    Clone,
+    Copy,
+    Serialize,
+    Deserialize,
+    Display,
+    Hash,
+    Eq,
+    PartialOrd,
+    Ord,
+    ToSchema,
 )]
 #[strum(serialize_all = "snake_case")]
 #[serde(rename_all = "snake_case")]
@@ -66,7 +122,18 @@ pub enum Pronoun {
 }
 
 #[derive(
-    Debug, PartialEq, Clone, Copy, Serialize, Deserialize, Display, Hash, Eq, PartialOrd, Ord,
+    Debug,
+    PartialEq,
+    Clone,
+    Copy,
+    Serialize,
+    Deserialize,
+    Display,
+    Hash,
+    Eq,
+    PartialOrd,
+    Ord,
+    ToSchema,
 )]
 #[strum(serialize_all = "snake_case")]
 #[serde(rename_all = "snake_case")]
@@ -79,7 +146,18 @@ pub enum Article {
 }
 
 #[derive(
-    Debug, PartialEq, Clone, Copy, Serialize, Deserialize, Display, Hash, Eq, PartialOrd, Ord,
+    Debug,
+    PartialEq,
+    Clone,
+    Copy,
+    Serialize,
+    Deserialize,
+    Display,
+    Hash,
+    Eq,
+    PartialOrd,
+    Ord,
+    ToSchema,
 )]
 #[strum(serialize_all = "snake_case")]
 #[serde(rename_all = "snake_case")]
@@ -94,7 +172,18 @@ pub enum Adjective {
 }
 
 #[derive(
-    Debug, PartialEq, Clone, Copy, Serialize, Deserialize, Display, Hash, Eq, PartialOrd, Ord,
+    Debug,
+    PartialEq,
+    Clone,
+    Copy,
+    Serialize,
+    Deserialize,
+    Display,
+    Hash,
+    Eq,
+    PartialOrd,
+    Ord,
+    ToSchema,
 )]
 #[strum(serialize_all = "snake_case")]
 #[serde(rename_all = "snake_case")]
@@ -109,7 +198,18 @@ pub enum Numeral {
 }
 
 #[derive(
-    Debug, PartialEq, Clone, Copy, Serialize, Deserialize, Display, Hash, Eq, PartialOrd, Ord,
+    Debug,
+    PartialEq,
+    Clone,
+    Copy,
+    Serialize,
+    Deserialize,
+    Display,
+    Hash,
+    Eq,
+    PartialOrd,
+    Ord,
+    ToSchema,
 )]
 #[strum(serialize_all = "snake_case")]
 #[serde(rename_all = "snake_case")]
@@ -120,7 +220,18 @@ pub enum Gender {
 }
 
 #[derive(
-    Debug, PartialEq, Clone, Copy, Serialize, Deserialize, Display, Hash, Eq, PartialOrd, Ord,
+    Debug,
+    PartialEq,
+    Clone,
+    Copy,
+    Serialize,
+    Deserialize,
+    Display,
+    Hash,
+    Eq,
+    PartialOrd,
+    Ord,
+    ToSchema,
 )]
 #[strum(serialize_all = "snake_case")]
 #[serde(rename_all = "snake_case")]
@@ -131,7 +242,18 @@ pub enum Number {
 }
 
 #[derive(
-    Debug, PartialEq, Clone, Copy, Serialize, Deserialize, Display, Hash, Eq, PartialOrd, Ord,
+    Debug,
+    PartialEq,
+    Clone,
+    Copy,
+    Serialize,
+    Deserialize,
+    Display,
+    Hash,
+    Eq,
+    PartialOrd,
+    Ord,
+    ToSchema,
 )]
 #[strum(serialize_all = "snake_case")]
 #[serde(rename_all = "snake_case")]
@@ -142,7 +264,18 @@ pub enum Person {
 }
 
 #[derive(
-    Debug, PartialEq, Clone, Copy, Serialize, Deserialize, Display, Hash, Eq, PartialOrd, Ord,
+    Debug,
+    PartialEq,
+    Clone,
+    Copy,
+    Serialize,
+    Deserialize,
+    Display,
+    Hash,
+    Eq,
+    PartialOrd,
+    Ord,
+    ToSchema,
 )]
 #[strum(serialize_all = "snake_case")]
 #[serde(rename_all = "snake_case")]
@@ -156,7 +289,18 @@ pub enum Case {
 }
 
 #[derive(
-    Debug, PartialEq, Clone, Copy, Serialize, Deserialize, Display, Hash, Eq, PartialOrd, Ord,
+    Debug,
+    PartialEq,
+    Clone,
+    Copy,
+    Serialize,
+    Deserialize,
+    Display,
+    Hash,
+    Eq,
+    PartialOrd,
+    Ord,
+    ToSchema,
 )]
 #[strum(serialize_all = "snake_case")]
 #[serde(rename_all = "snake_case")]
@@ -167,7 +311,18 @@ pub enum Voice {
 }
 
 #[derive(
-    Debug, PartialEq, Clone, Copy, Serialize, Deserialize, Display, Hash, Eq, PartialOrd, Ord,
+    Debug,
+    PartialEq,
+    Clone,
+    Copy,
+    Serialize,
+    Deserialize,
+    Display,
+    Hash,
+    Eq,
+    PartialOrd,
+    Ord,
+    ToSchema,
 )]
 #[strum(serialize_all = "snake_case")]
 #[serde(rename_all = "snake_case")]
@@ -181,7 +336,18 @@ pub enum Mood {
 }
 
 #[derive(
-    Debug, PartialEq, Clone, Copy, Serialize, Deserialize, Display, Hash, Eq, PartialOrd, Ord,
+    Debug,
+    PartialEq,
+    Clone,
+    Copy,
+    Serialize,
+    Deserialize,
+    Display,
+    Hash,
+    Eq,
+    PartialOrd,
+    Ord,
+    ToSchema,
 )]
 #[strum(serialize_all = "snake_case")]
 #[serde(rename_all = "snake_case")]
@@ -196,7 +362,18 @@ pub enum Tense {
 }
 
 #[derive(
-    Debug, PartialEq, Clone, Copy, Serialize, Deserialize, Display, Hash, Eq, PartialOrd, Ord,
+    Debug,
+    PartialEq,
+    Clone,
+    Copy,
+    Serialize,
+    Deserialize,
+    Display,
+    Hash,
+    Eq,
+    PartialOrd,
+    Ord,
+    ToSchema,
 )]
 #[strum(serialize_all = "snake_case")]
 #[serde(rename_all = "snake_case")]
@@ -207,7 +384,18 @@ pub enum Theme {
 }
 
 #[derive(
-    Debug, PartialEq, Clone, Copy, Serialize, Deserialize, Display, Hash, Eq, PartialOrd, Ord,
+    Debug,
+    PartialEq,
+    Clone,
+    Copy,
+    Serialize,
+    Deserialize,
+    Display,
+    Hash,
+    Eq,
+    PartialOrd,
+    Ord,
+    ToSchema,
 )]
 #[strum(serialize_all = "snake_case")]
 #[serde(rename_all = "snake_case")]
@@ -218,7 +406,18 @@ pub enum Contraction {
 }
 
 #[derive(
-    Debug, PartialEq, Clone, Copy, Serialize, Deserialize, Display, Hash, PartialOrd, Eq, Ord,
+    Debug,
+    PartialEq,
+    Clone,
+    Copy,
+    Serialize,
+    Deserialize,
+    Display,
+    Hash,
+    PartialOrd,
+    Eq,
+    Ord,
+    ToSchema,
 )]
 #[strum(serialize_all = "snake_case")]
 #[serde(rename_all = "snake_case")]
@@ -230,7 +429,7 @@ pub enum DeclensionType {
     Indeclinable,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash, ToSchema)]
 #[serde(rename_all = "camelCase")]
 #[serde_with::skip_serializing_none]
 pub struct Declension {
@@ -265,8 +464,32 @@ impl Declension {
     }
 }
 
+/// Long parsing string, e.g. "aorist active indicative 3rd person singular", or the short one
+/// ("aor act ind 3s") with `{:#}`.
+impl fmt::Display for Declension {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let style = match f.alternate() {
+            true => ParseStyle::Short,
+            false => ParseStyle::Long,
+        };
+
+        write!(f, "{}", self.format(style))
+    }
+}
+
 #[derive(
-    Debug, PartialEq, Clone, Copy, Serialize, Deserialize, Display, Hash, Eq, PartialOrd, Ord,
+    Debug,
+    PartialEq,
+    Clone,
+    Copy,
+    Serialize,
+    Deserialize,
+    Display,
+    Hash,
+    Eq,
+    PartialOrd,
+    Ord,
+    ToSchema,
 )]
 #[strum(serialize_all = "snake_case")]
 #[serde(rename_all = "snake_case")]
@@ -276,7 +499,18 @@ pub enum Language {
 }
 
 #[derive(
-    Debug, PartialEq, Clone, Copy, Serialize, Deserialize, Display, Hash, Eq, PartialOrd, Ord,
+    Debug,
+    PartialEq,
+    Clone,
+    Copy,
+    Serialize,
+    Deserialize,
+    Display,
+    Hash,
+    Eq,
+    PartialOrd,
+    Ord,
+    ToSchema,
 )]
 #[strum(serialize_all = "snake_case")]
 #[serde(rename_all = "snake_case")]
@@ -294,16 +528,19 @@ impl Language {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct Word {
     pub language: LanguageCode,
     pub text: String,
     pub translation: HashMap<LanguageCode, String>,
     pub declension: Declension,
+    #[serde(default)]
+    pub lemma: Option<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct Verse {
     pub collection: Collection,
@@ -315,7 +552,18 @@ pub struct Verse {
 }
 
 #[derive(
-    Debug, PartialEq, Clone, Copy, Serialize, Deserialize, Display, Hash, Eq, PartialOrd, Ord,
+    Debug,
+    PartialEq,
+    Clone,
+    Copy,
+    Serialize,
+    Deserialize,
+    Display,
+    Hash,
+    Eq,
+    PartialOrd,
+    Ord,
+    ToSchema,
 )]
 #[strum(serialize_all = "snake_case")]
 #[serde(rename_all = "snake_case")]
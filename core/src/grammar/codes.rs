@@ -0,0 +1,80 @@
+use thiserror::Error;
+
+use super::{Case, Gender, Number, Person, Voice};
+
+pub mod morphgnt;
+pub mod robinson;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum CodeError {
+    #[error("malformed morphology code {0}")]
+    Malformed(String),
+    #[error("unknown {field} '{value}' in morphology code {code}")]
+    Unknown {
+        code: String,
+        field: &'static str,
+        value: String,
+    },
+    #[error("impossible declension: {0}")]
+    Impossible(String),
+    #[error("declension has no {field}, needed by {system}")]
+    Missing {
+        system: &'static str,
+        field: &'static str,
+    },
+    #[error("{value} cannot be encoded in {system}")]
+    Unencodable { system: &'static str, value: String },
+}
+
+const PERSONS: [(char, Person); 3] = [
+    ('1', Person::First),
+    ('2', Person::Second),
+    ('3', Person::Third),
+];
+
+const VOICES: [(char, Voice); 3] = [
+    ('A', Voice::Active),
+    ('M', Voice::Middle),
+    ('P', Voice::Passive),
+];
+
+const CASES: [(char, Case); 5] = [
+    ('N', Case::Nominative),
+    ('G', Case::Genitive),
+    ('D', Case::Dative),
+    ('A', Case::Accusative),
+    ('V', Case::Vocative),
+];
+
+const NUMBERS: [(char, Number); 2] = [('S', Number::Singular), ('P', Number::Plural)];
+
+const GENDERS: [(char, Gender); 3] = [
+    ('M', Gender::Masculine),
+    ('F', Gender::Feminine),
+    ('N', Gender::Neuter),
+];
+
+fn decode<T: Copy>(table: &[(char, T)], letter: char) -> Option<T> {
+    table.iter().find(|(x, _)| *x == letter).map(|(_, x)| *x)
+}
+
+fn encode<T: PartialEq>(table: &[(char, T)], value: &T) -> Option<char> {
+    table.iter().find(|(_, x)| x == value).map(|(x, _)| *x)
+}
+
+/// Letter of `value` in `table`, or why it has none.
+fn encode_field<T: PartialEq + ToString>(
+    system: &'static str,
+    field: &'static str,
+    table: &[(char, T)],
+    value: &Option<T>,
+) -> Result<char, CodeError> {
+    let value = value
+        .as_ref()
+        .ok_or(CodeError::Missing { system, field })?;
+
+    encode(table, value).ok_or_else(|| CodeError::Unencodable {
+        system,
+        value: value.to_string(),
+    })
+}
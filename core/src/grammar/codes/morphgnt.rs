@@ -0,0 +1,220 @@
+use crate::grammar::{Adjective, Article, Declension, Mood, Noun, PartOfSpeech, Pronoun, Tense};
+
+use super::{decode, encode, CodeError, CASES, GENDERS, NUMBERS, PERSONS, VOICES};
+
+const SYSTEM: &str = "MorphGNT";
+
+const TENSES: [(char, Tense); 6] = [
+    ('P', Tense::Present),
+    ('I', Tense::Imperfect),
+    ('F', Tense::Future),
+    ('A', Tense::Aorist),
+    ('X', Tense::Perfect),
+    ('Y', Tense::Pluperfect),
+];
+
+const MOODS: [(char, Mood); 6] = [
+    ('I', Mood::Indicative),
+    ('D', Mood::Imperative),
+    ('S', Mood::Subjunctive),
+    ('O', Mood::Optative),
+    ('N', Mood::Infinitive),
+    ('P', Mood::Participle),
+];
+
+const DEGREES: [(char, Adjective); 2] =
+    [('C', Adjective::Comparative), ('S', Adjective::Superlative)];
+
+impl Declension {
+    /// Parses a MorphGNT part of speech and parsing code, either as in the SBLGNT files
+    /// (`V- 3AAI-S--`) or run together (`V-3AAI-S--`).
+    pub fn from_morphgnt(code: &str) -> Result<Declension, CodeError> {
+        let code = code.trim().to_uppercase();
+        let malformed = || CodeError::Malformed(code.clone());
+        let unknown = |field: &'static str, value: char| CodeError::Unknown {
+            code: code.clone(),
+            field,
+            value: value.to_string(),
+        };
+
+        let letters = code
+            .chars()
+            .filter(|x| !x.is_whitespace())
+            .collect::<Vec<_>>();
+        let [p1, p2, person, tense, voice, mood, case, number, gender, degree] = letters[..] else {
+            return Err(malformed());
+        };
+
+        let part_of_speech = match (p1, p2) {
+            ('A', '-') => match degree {
+                '-' => PartOfSpeech::Adjective(Adjective::Positive),
+                x => PartOfSpeech::Adjective(decode(&DEGREES, x).ok_or(unknown("degree", x))?),
+            },
+            ('C' | 'X', '-') => PartOfSpeech::Particle,
+            ('D', '-') => PartOfSpeech::Adverb,
+            ('I', '-') => PartOfSpeech::Interjection,
+            ('N', '-') => PartOfSpeech::Noun(Noun::Common),
+            ('P', '-') => PartOfSpeech::Preposition,
+            ('R', 'A') => PartOfSpeech::Article(Article::Definite),
+            ('R', 'D') => PartOfSpeech::Pronoun(Pronoun::Demonstrative),
+            ('R', 'I') => PartOfSpeech::Pronoun(Pronoun::Interrogative),
+            ('R', 'P') => PartOfSpeech::Pronoun(Pronoun::Personal),
+            ('R', 'R') => PartOfSpeech::Pronoun(Pronoun::Relative),
+            ('V', '-') => PartOfSpeech::Verb,
+            _ => {
+                return Err(CodeError::Unknown {
+                    code: code.clone(),
+                    field: "part of speech",
+                    value: format!("{p1}{p2}"),
+                })
+            }
+        };
+        let mut declension = Declension::partial_default(part_of_speech);
+
+        declension.person = field(&code, &PERSONS, "person", person)?;
+        declension.tense = field(&code, &TENSES, "tense", tense)?;
+        declension.voice = field(&code, &VOICES, "voice", voice)?;
+        declension.mood = field(&code, &MOODS, "mood", mood)?;
+        declension.case = field(&code, &CASES, "case", case)?;
+        declension.number = field(&code, &NUMBERS, "number", number)?;
+        declension.gender = field(&code, &GENDERS, "gender", gender)?;
+
+        declension.validate()?;
+        Ok(declension)
+    }
+
+    /// MorphGNT part of speech and parsing code, space separated as in the SBLGNT files.
+    pub fn to_morphgnt(&self) -> Result<String, CodeError> {
+        self.validate()?;
+
+        let part_of_speech = match self.part_of_speech {
+            PartOfSpeech::Verb => "V-",
+            PartOfSpeech::Adverb => "D-",
+            PartOfSpeech::Preposition => "P-",
+            PartOfSpeech::Particle => "X-",
+            PartOfSpeech::Interjection => "I-",
+            PartOfSpeech::Quantifier | PartOfSpeech::Numeral(_) | PartOfSpeech::Adjective(_) => {
+                "A-"
+            }
+            PartOfSpeech::Noun(_) => "N-",
+            PartOfSpeech::Article(Article::Definite) => "RA",
+            PartOfSpeech::Pronoun(Pronoun::Demonstrative) => "RD",
+            PartOfSpeech::Pronoun(Pronoun::Interrogative | Pronoun::Indefinite) => "RI",
+            PartOfSpeech::Pronoun(Pronoun::Personal | Pronoun::Reflexive | Pronoun::Reciprocal) => {
+                "RP"
+            }
+            PartOfSpeech::Pronoun(Pronoun::Relative) => "RR",
+            PartOfSpeech::Article(Article::Indefinite) => {
+                return Err(CodeError::Unencodable {
+                    system: SYSTEM,
+                    value: self.to_string(),
+                })
+            }
+        };
+
+        let degree = match self.part_of_speech {
+            PartOfSpeech::Adjective(x) => encode(&DEGREES, &x).unwrap_or('-'),
+            _ => '-',
+        };
+
+        Ok(format!(
+            "{part_of_speech} {}{}{}{}{}{}{}{degree}",
+            letter(&PERSONS, &self.person)?,
+            letter(&TENSES, &self.tense)?,
+            letter(&VOICES, &self.voice)?,
+            letter(&MOODS, &self.mood)?,
+            letter(&CASES, &self.case)?,
+            letter(&NUMBERS, &self.number)?,
+            letter(&GENDERS, &self.gender)?,
+        ))
+    }
+}
+
+/// Value of an optional field, unset on `-`.
+fn field<T: Copy>(
+    code: &str,
+    table: &[(char, T)],
+    field: &'static str,
+    letter: char,
+) -> Result<Option<T>, CodeError> {
+    match letter {
+        '-' => Ok(None),
+        x => decode(table, x)
+            .map(Some)
+            .ok_or_else(|| CodeError::Unknown {
+                code: code.to_string(),
+                field,
+                value: x.to_string(),
+            }),
+    }
+}
+
+/// Letter of an optional field, `-` when unset.
+fn letter<T: PartialEq + ToString>(
+    table: &[(char, T)],
+    value: &Option<T>,
+) -> Result<char, CodeError> {
+    match value {
+        Some(x) => encode(table, x).ok_or_else(|| CodeError::Unencodable {
+            system: SYSTEM,
+            value: x.to_string(),
+        }),
+        None => Ok('-'),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grammar::{Case, Gender, Number, Person, Voice};
+
+    #[test]
+    fn round_trips_codes() {
+        for code in [
+            "V- 3AAI-S--",
+            "V- 1PAI-S--",
+            "V- -AAN----",
+            "V- -PMPGPF-",
+            "N- ----NSF-",
+            "A- ----NSM-",
+            "A- ----ASNC",
+            "RA ----NPM-",
+            "RD ----ASN-",
+            "RP ----GS--",
+            "RR ----DSF-",
+            "D- --------",
+            "P- --------",
+            "X- --------",
+        ] {
+            let declension = Declension::from_morphgnt(code).unwrap();
+            assert_eq!(declension.to_morphgnt().unwrap(), code);
+        }
+    }
+
+    #[test]
+    fn decodes_codes_run_together() {
+        let declension = Declension::from_morphgnt("V-3AAI-S--").unwrap();
+        assert_eq!(declension.part_of_speech, PartOfSpeech::Verb);
+        assert_eq!(declension.person, Some(Person::Third));
+        assert_eq!(declension.tense, Some(Tense::Aorist));
+        assert_eq!(declension.voice, Some(Voice::Active));
+        assert_eq!(declension.mood, Some(Mood::Indicative));
+        assert_eq!(declension.number, Some(Number::Singular));
+
+        let declension = Declension::from_morphgnt("n- ----gpm-").unwrap();
+        assert_eq!(declension.case, Some(Case::Genitive));
+        assert_eq!(declension.gender, Some(Gender::Masculine));
+    }
+
+    #[test]
+    fn rejects_bad_codes() {
+        assert_eq!(
+            Declension::from_morphgnt("V- 3AAI"),
+            Err(CodeError::Malformed("V- 3AAI".to_string()))
+        );
+        assert!(matches!(
+            Declension::from_morphgnt("V- 3ZAI-S--"),
+            Err(CodeError::Unknown { field: "tense", .. })
+        ));
+    }
+}
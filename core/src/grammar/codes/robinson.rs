@@ -0,0 +1,352 @@
+use crate::grammar::{
+    Adjective, Article, Declension, Mood, Noun, Numeral, PartOfSpeech, Person, Pronoun, Tense,
+    Voice,
+};
+
+use super::{decode, encode_field, CodeError, CASES, GENDERS, NUMBERS, PERSONS, VOICES};
+
+const SYSTEM: &str = "Robinson";
+
+const TENSES: [(char, Tense); 6] = [
+    ('P', Tense::Present),
+    ('I', Tense::Imperfect),
+    ('F', Tense::Future),
+    ('A', Tense::Aorist),
+    ('R', Tense::Perfect),
+    ('L', Tense::Pluperfect),
+];
+
+const MOODS: [(char, Mood); 6] = [
+    ('I', Mood::Indicative),
+    ('S', Mood::Subjunctive),
+    ('O', Mood::Optative),
+    ('M', Mood::Imperative),
+    ('N', Mood::Infinitive),
+    ('P', Mood::Participle),
+];
+
+/// Trailing flags that add nothing a `Declension` can hold (attic, apocopated, interrogative...).
+const IGNORED_SUFFIXES: [&str; 11] = ["ATT", "APO", "ABB", "C", "S", "I", "N", "K", "L", "T", "P"];
+
+impl Declension {
+    /// Parses a Robinson code such as `V-AAI-3S`, `V-2AAP-NSM` or `N-GSM`.
+    pub fn from_robinson(code: &str) -> Result<Declension, CodeError> {
+        let code = code.trim().to_uppercase();
+        let parser = Parser { code: &code };
+        let parts = code.split('-').collect::<Vec<_>>();
+        let (pos, rest) = parts.split_first().ok_or(parser.malformed())?;
+
+        let (mut declension, used) = match *pos {
+            "V" => parser.verb(rest)?,
+            "ADV" => (Declension::partial_default(PartOfSpeech::Adverb), 0),
+            "CONJ" | "COND" | "PRT" => (Declension::partial_default(PartOfSpeech::Particle), 0),
+            "PREP" => (Declension::partial_default(PartOfSpeech::Preposition), 0),
+            "INJ" => (Declension::partial_default(PartOfSpeech::Interjection), 0),
+            "N" | "A" | "T" | "R" | "C" | "D" | "K" | "I" | "X" | "Q" | "F" | "S" | "P" => {
+                parser.nominal(pos, rest)?
+            }
+            x => return Err(parser.unknown("part of speech", x)),
+        };
+
+        for suffix in &rest[used..] {
+            match (declension.part_of_speech, *suffix) {
+                (PartOfSpeech::Adjective(_), "C") => {
+                    declension.part_of_speech = PartOfSpeech::Adjective(Adjective::Comparative)
+                }
+                (PartOfSpeech::Adjective(_), "S") => {
+                    declension.part_of_speech = PartOfSpeech::Adjective(Adjective::Superlative)
+                }
+                (_, x) if IGNORED_SUFFIXES.contains(&x) => {}
+                (_, x) => return Err(parser.unknown("suffix", x)),
+            }
+        }
+
+        declension.validate()?;
+        Ok(declension)
+    }
+
+    /// Robinson code of a full declension, e.g. `V-AAI-3S`.
+    pub fn to_robinson(&self) -> Result<String, CodeError> {
+        self.validate()?;
+
+        let code = match self.part_of_speech {
+            PartOfSpeech::Verb => {
+                let tense = encode_field(SYSTEM, "tense", &TENSES, &self.tense)?;
+                let voice = encode_field(SYSTEM, "voice", &VOICES, &self.voice)?;
+                let mood = encode_field(SYSTEM, "mood", &MOODS, &self.mood)?;
+                match self.mood {
+                    Some(Mood::Infinitive) => format!("V-{tense}{voice}{mood}"),
+                    Some(Mood::Participle) => format!("V-{tense}{voice}{mood}-{}", self.cng()?),
+                    _ => format!(
+                        "V-{tense}{voice}{mood}-{}{}",
+                        encode_field(SYSTEM, "person", &PERSONS, &self.person)?,
+                        encode_field(SYSTEM, "number", &NUMBERS, &self.number)?
+                    ),
+                }
+            }
+            PartOfSpeech::Adverb => "ADV".to_string(),
+            PartOfSpeech::Preposition => "PREP".to_string(),
+            PartOfSpeech::Particle => "PRT".to_string(),
+            PartOfSpeech::Interjection => "INJ".to_string(),
+            PartOfSpeech::Noun(Noun::Proper) if self.case.is_none() => "N-PRI".to_string(),
+            PartOfSpeech::Noun(_) => format!("N-{}", self.cng()?),
+            PartOfSpeech::Article(Article::Definite) => format!("T-{}", self.cng()?),
+            PartOfSpeech::Article(Article::Indefinite) => {
+                return Err(CodeError::Unencodable {
+                    system: SYSTEM,
+                    value: self.to_string(),
+                })
+            }
+            PartOfSpeech::Adjective(Adjective::Positive) => format!("A-{}", self.cng()?),
+            PartOfSpeech::Adjective(Adjective::Comparative) => format!("A-{}-C", self.cng()?),
+            PartOfSpeech::Adjective(Adjective::Superlative) => format!("A-{}-S", self.cng()?),
+            PartOfSpeech::Numeral(_) if self.case.is_none() => "A-NUI".to_string(),
+            PartOfSpeech::Numeral(_) | PartOfSpeech::Quantifier => format!("A-{}", self.cng()?),
+            PartOfSpeech::Pronoun(Pronoun::Relative) => format!("R-{}", self.cng()?),
+            PartOfSpeech::Pronoun(Pronoun::Reciprocal) => format!("C-{}", self.cng()?),
+            PartOfSpeech::Pronoun(Pronoun::Demonstrative) => format!("D-{}", self.cng()?),
+            PartOfSpeech::Pronoun(Pronoun::Interrogative) => format!("I-{}", self.cng()?),
+            PartOfSpeech::Pronoun(Pronoun::Indefinite) => format!("X-{}", self.cng()?),
+            PartOfSpeech::Pronoun(Pronoun::Personal) => match self.person {
+                Some(Person::First | Person::Second) => format!(
+                    "P-{}{}{}",
+                    encode_field(SYSTEM, "person", &PERSONS, &self.person)?,
+                    encode_field(SYSTEM, "case", &CASES, &self.case)?,
+                    encode_field(SYSTEM, "number", &NUMBERS, &self.number)?
+                ),
+                _ => format!("P-{}", self.cng()?),
+            },
+            PartOfSpeech::Pronoun(Pronoun::Reflexive) => format!(
+                "F-{}{}",
+                encode_field(SYSTEM, "person", &PERSONS, &self.person)?,
+                self.cng()?
+            ),
+        };
+
+        Ok(code)
+    }
+
+    fn cng(&self) -> Result<String, CodeError> {
+        Ok(format!(
+            "{}{}{}",
+            encode_field(SYSTEM, "case", &CASES, &self.case)?,
+            encode_field(SYSTEM, "number", &NUMBERS, &self.number)?,
+            encode_field(SYSTEM, "gender", &GENDERS, &self.gender)?
+        ))
+    }
+}
+
+struct Parser<'a> {
+    code: &'a str,
+}
+
+impl Parser<'_> {
+    fn malformed(&self) -> CodeError {
+        CodeError::Malformed(self.code.to_string())
+    }
+
+    fn unknown(&self, field: &'static str, value: impl ToString) -> CodeError {
+        CodeError::Unknown {
+            code: self.code.to_string(),
+            field,
+            value: value.to_string(),
+        }
+    }
+
+    /// `V-{tense}{voice}{mood}`, then `-{person}{number}` or `-{case}{number}{gender}`. A `2`
+    /// before the tense (second aorist, future or perfect) and the deponent voices are folded.
+    fn verb(&self, rest: &[&str]) -> Result<(Declension, usize), CodeError> {
+        let mut declension = Declension::partial_default(PartOfSpeech::Verb);
+        let tvm = rest.first().ok_or(self.malformed())?;
+        let tvm = tvm
+            .strip_prefix('2')
+            .unwrap_or(tvm)
+            .chars()
+            .collect::<Vec<_>>();
+        let [tense, voice, mood] = tvm[..] else {
+            return Err(self.malformed());
+        };
+
+        declension.tense = match tense {
+            'X' => None,
+            x => Some(decode(&TENSES, x).ok_or(self.unknown("tense", x))?),
+        };
+        declension.voice = match voice {
+            'X' => None,
+            'E' | 'D' | 'N' => Some(Voice::Middle),
+            'O' => Some(Voice::Passive),
+            'Q' => Some(Voice::Active),
+            x => Some(decode(&VOICES, x).ok_or(self.unknown("voice", x))?),
+        };
+        declension.mood = match mood {
+            'R' => Some(Mood::Participle),
+            x => Some(decode(&MOODS, x).ok_or(self.unknown("mood", x))?),
+        };
+
+        match declension.mood {
+            Some(Mood::Infinitive) => Ok((declension, 1)),
+            Some(Mood::Participle) => {
+                let group = rest.get(1).ok_or(self.malformed())?;
+                self.fill_cng(&mut declension, group)?;
+                Ok((declension, 2))
+            }
+            _ => {
+                let group = rest.get(1).ok_or(self.malformed())?;
+                let [person, number] = group.chars().collect::<Vec<_>>()[..] else {
+                    return Err(self.malformed());
+                };
+                declension.person =
+                    Some(decode(&PERSONS, person).ok_or(self.unknown("person", person))?);
+                declension.number =
+                    Some(decode(&NUMBERS, number).ok_or(self.unknown("number", number))?);
+                Ok((declension, 2))
+            }
+        }
+    }
+
+    fn nominal(&self, pos: &str, rest: &[&str]) -> Result<(Declension, usize), CodeError> {
+        let group = *rest.first().ok_or(self.malformed())?;
+
+        let part_of_speech = match (pos, group) {
+            ("N", "PRI") => {
+                return Ok((
+                    Declension::partial_default(PartOfSpeech::Noun(Noun::Proper)),
+                    1,
+                ))
+            }
+            ("N", "LI") => {
+                return Ok((
+                    Declension::partial_default(PartOfSpeech::Noun(Noun::Common)),
+                    1,
+                ))
+            }
+            ("N" | "A", "OI" | "NUI") => {
+                return Ok((
+                    Declension::partial_default(PartOfSpeech::Numeral(Numeral::Cardinal)),
+                    1,
+                ))
+            }
+            ("N", _) => PartOfSpeech::Noun(Noun::Common),
+            ("A", _) => PartOfSpeech::Adjective(Adjective::Positive),
+            ("T", _) => PartOfSpeech::Article(Article::Definite),
+            ("R", _) => PartOfSpeech::Pronoun(Pronoun::Relative),
+            ("C", _) => PartOfSpeech::Pronoun(Pronoun::Reciprocal),
+            ("D" | "K", _) => PartOfSpeech::Pronoun(Pronoun::Demonstrative),
+            ("I" | "Q", _) => PartOfSpeech::Pronoun(Pronoun::Interrogative),
+            ("X", _) => PartOfSpeech::Pronoun(Pronoun::Indefinite),
+            ("F", _) => PartOfSpeech::Pronoun(Pronoun::Reflexive),
+            _ => PartOfSpeech::Pronoun(Pronoun::Personal),
+        };
+        let mut declension = Declension::partial_default(part_of_speech);
+
+        let mut group = group;
+        if matches!(pos, "P" | "F" | "S") {
+            if let Some(person) = group.chars().next().and_then(|x| decode(&PERSONS, x)) {
+                declension.person = Some(person);
+                group = &group[1..];
+                // possessives carry the possessor's number before the possessed word's parse
+                if pos == "S" {
+                    group = group.get(1..).ok_or(self.malformed())?;
+                }
+            }
+        }
+
+        self.fill_cng(&mut declension, group)?;
+        Ok((declension, 1))
+    }
+
+    /// `{case}{number}`, optionally followed by `{gender}`.
+    fn fill_cng(&self, declension: &mut Declension, group: &str) -> Result<(), CodeError> {
+        let letters = group.chars().collect::<Vec<_>>();
+        let (case, number, gender) = match letters[..] {
+            [case, number] => (case, number, None),
+            [case, number, gender] => (case, number, Some(gender)),
+            _ => return Err(self.malformed()),
+        };
+
+        declension.case = Some(decode(&CASES, case).ok_or(self.unknown("case", case))?);
+        declension.number = Some(decode(&NUMBERS, number).ok_or(self.unknown("number", number))?);
+        declension.gender = match gender {
+            Some(x) => Some(decode(&GENDERS, x).ok_or(self.unknown("gender", x))?),
+            None => None,
+        };
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grammar::{Case, Gender, Number};
+
+    #[test]
+    fn round_trips_codes() {
+        for code in [
+            "V-PAI-1S",
+            "V-AAI-3S",
+            "V-APN",
+            "V-PMP-GPF",
+            "N-GSM",
+            "N-PRI",
+            "A-NSF",
+            "A-ASN-C",
+            "T-NPM",
+            "R-DSF",
+            "D-ASN",
+            "I-NSM",
+            "X-NSN",
+            "P-1GS",
+            "P-GSM",
+            "F-3GSM",
+            "ADV",
+            "PREP",
+            "PRT",
+            "INJ",
+        ] {
+            let declension = Declension::from_robinson(code).unwrap();
+            assert_eq!(declension.to_robinson().unwrap(), code);
+        }
+    }
+
+    #[test]
+    fn folds_second_tenses_and_deponents() {
+        let declension = Declension::from_robinson("V-2AAP-NSM").unwrap();
+        assert_eq!(declension.tense, Some(Tense::Aorist));
+        assert_eq!(declension.mood, Some(Mood::Participle));
+        assert_eq!(declension.case, Some(Case::Nominative));
+        assert_eq!(declension.number, Some(Number::Singular));
+        assert_eq!(declension.gender, Some(Gender::Masculine));
+
+        assert_eq!(declension.to_robinson().unwrap(), "V-AAP-NSM");
+
+        let declension = Declension::from_robinson("v-2rai-3s").unwrap();
+        assert_eq!(declension.tense, Some(Tense::Perfect));
+
+        let declension = Declension::from_robinson("V-PNI-3S").unwrap();
+        assert_eq!(declension.voice, Some(Voice::Middle));
+        assert_eq!(declension.to_robinson().unwrap(), "V-PMI-3S");
+    }
+
+    #[test]
+    fn rejects_bad_codes() {
+        assert_eq!(
+            Declension::from_robinson("V-AA"),
+            Err(CodeError::Malformed("V-AA".to_string()))
+        );
+        assert!(matches!(
+            Declension::from_robinson("Z-NSM"),
+            Err(CodeError::Unknown {
+                field: "part of speech",
+                ..
+            })
+        ));
+        assert!(matches!(
+            Declension::from_robinson("N-GSM-Z"),
+            Err(CodeError::Unknown {
+                field: "suffix",
+                ..
+            })
+        ));
+    }
+}
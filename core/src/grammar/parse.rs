@@ -0,0 +1,260 @@
+use std::{collections::HashMap, fs};
+
+use once_cell::sync::OnceCell;
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+use utoipa::ToSchema;
+
+use crate::{config::EnvVar, error::SafeError};
+
+use super::Declension;
+
+static LABELS: OnceCell<ParseLabels> = OnceCell::new();
+
+#[derive(Debug, Default, PartialEq, Clone, Copy, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ParseStyle {
+    /// "aorist active indicative 3rd person singular"
+    #[default]
+    Long,
+    /// "aor act ind 3s"
+    Short,
+}
+
+/// Labels keyed by the serialized name of each value (`aorist`, `genitive`, `noun_common`...).
+/// Missing keys fall back to the name itself, and an empty label hides the value. In the short
+/// style a person and a number are joined under `{person}_{number}` (`third_singular`).
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ParseLabels {
+    #[serde(default)]
+    pub long: HashMap<String, String>,
+    #[serde(default)]
+    pub short: HashMap<String, String>,
+}
+
+impl ParseLabels {
+    /// Labels set by the `PARSE_LABELS` json file, over the english ones.
+    pub fn get() -> &'static ParseLabels {
+        LABELS.get_or_init(|| {
+            let mut labels = ParseLabels::english();
+            if let Ok(path) = EnvVar::ParseLabels.get::<String>() {
+                match ParseLabels::from_file(&path) {
+                    Ok(custom) => labels.extend(custom),
+                    Err(e) => warn!("could not load parse labels from {path}: {e}"),
+                }
+            }
+            labels
+        })
+    }
+
+    pub fn from_file(path: &str) -> Result<ParseLabels, SafeError> {
+        Ok(serde_json::from_str(&fs::read_to_string(path)?)?)
+    }
+
+    pub fn extend(&mut self, other: ParseLabels) {
+        self.long.extend(other.long);
+        self.short.extend(other.short);
+    }
+
+    pub fn english() -> ParseLabels {
+        let mut long = HashMap::new();
+        let mut short = HashMap::new();
+
+        let mut insert = |key: &str, long_label: &str, short_label: &str| {
+            long.insert(key.to_string(), long_label.to_string());
+            short.insert(key.to_string(), short_label.to_string());
+        };
+
+        insert("verb", "", "");
+        insert("adverb", "adverb", "adv");
+        insert("preposition", "preposition", "prep");
+        insert("particle", "particle", "part");
+        insert("interjection", "interjection", "interj");
+        insert("quantifier", "quantifier", "quant");
+        insert("noun_common", "common noun", "n");
+        insert("noun_proper", "proper noun", "n prop");
+        insert("pronoun_relative", "relative pronoun", "pron rel");
+        insert(
+            "pronoun_interrogative",
+            "interrogative pronoun",
+            "pron interr",
+        );
+        insert("pronoun_indefinite", "indefinite pronoun", "pron indef");
+        insert("pronoun_reciprocal", "reciprocal pronoun", "pron recip");
+        insert("pronoun_reflexive", "reflexive pronoun", "pron refl");
+        insert("pronoun_demonstrative", "demonstrative pronoun", "pron dem");
+        insert("pronoun_personal", "personal pronoun", "pron pers");
+        insert("article_definite", "definite article", "art");
+        insert("article_indefinite", "indefinite article", "art indef");
+        insert("adjective_positive", "adjective", "adj");
+        insert("adjective_comparative", "comparative adjective", "adj comp");
+        insert("adjective_superlative", "superlative adjective", "adj sup");
+        insert("numeral_cardinal", "cardinal numeral", "num");
+        insert("numeral_ordinal", "ordinal numeral", "num ord");
+        insert("numeral_adverbial", "adverbial numeral", "num adv");
+
+        insert("present", "present", "pres");
+        insert("imperfect", "imperfect", "impf");
+        insert("future", "future", "fut");
+        insert("future_perfect", "future perfect", "fut pf");
+        insert("aorist", "aorist", "aor");
+        insert("perfect", "perfect", "pf");
+        insert("pluperfect", "pluperfect", "plpf");
+
+        insert("active", "active", "act");
+        insert("middle", "middle", "mid");
+        insert("passive", "passive", "pass");
+
+        insert("indicative", "indicative", "ind");
+        insert("subjunctive", "subjunctive", "subj");
+        insert("optative", "optative", "opt");
+        insert("imperative", "imperative", "impv");
+        insert("infinitive", "infinitive", "inf");
+        insert("participle", "participle", "ptc");
+
+        insert("first", "1st person", "1");
+        insert("second", "2nd person", "2");
+        insert("third", "3rd person", "3");
+
+        insert("nominative", "nominative", "nom");
+        insert("genitive", "genitive", "gen");
+        insert("dative", "dative", "dat");
+        insert("accusative", "accusative", "acc");
+        insert("vocative", "vocative", "voc");
+
+        insert("singular", "singular", "sg");
+        insert("dual", "dual", "du");
+        insert("plural", "plural", "pl");
+
+        insert("masculine", "masculine", "masc");
+        insert("feminine", "feminine", "fem");
+        insert("neuter", "neuter", "neut");
+
+        for (person, p) in [("first", "1"), ("second", "2"), ("third", "3")] {
+            for (number, n) in [("singular", "s"), ("dual", "d"), ("plural", "p")] {
+                short.insert(format!("{person}_{number}"), format!("{p}{n}"));
+            }
+        }
+
+        ParseLabels { long, short }
+    }
+
+    fn label(&self, style: ParseStyle, key: &str) -> String {
+        let labels = match style {
+            ParseStyle::Long => &self.long,
+            ParseStyle::Short => &self.short,
+        };
+
+        labels
+            .get(key)
+            .cloned()
+            .unwrap_or_else(|| key.replace('_', " "))
+    }
+}
+
+impl Declension {
+    /// Parsing string in the given style, e.g. "aorist active indicative 3rd person singular"
+    /// or "aor act ind 3s".
+    pub fn format_with(&self, style: ParseStyle, labels: &ParseLabels) -> String {
+        let mut keys = vec![key(&self.part_of_speech)];
+        keys.extend(self.tense.map(|x| key(&x)));
+        keys.extend(self.voice.map(|x| key(&x)));
+        keys.extend(self.mood.map(|x| key(&x)));
+
+        let mut parts = keys
+            .iter()
+            .map(|x| labels.label(style, x))
+            .collect::<Vec<_>>();
+
+        match (style, self.person, self.number) {
+            (ParseStyle::Short, Some(person), Some(number)) => {
+                let combined = format!("{}_{}", key(&person), key(&number));
+                parts.push(labels.short.get(&combined).cloned().unwrap_or_else(|| {
+                    labels.label(style, &key(&person)) + &labels.label(style, &key(&number))
+                }));
+                parts.extend(self.case.map(|x| labels.label(style, &key(&x))));
+            }
+            _ => {
+                parts.extend(self.person.map(|x| labels.label(style, &key(&x))));
+                parts.extend(self.case.map(|x| labels.label(style, &key(&x))));
+                parts.extend(self.number.map(|x| labels.label(style, &key(&x))));
+            }
+        }
+        parts.extend(self.gender.map(|x| labels.label(style, &key(&x))));
+
+        parts.retain(|x| !x.is_empty());
+        parts.join(" ")
+    }
+
+    pub fn format(&self, style: ParseStyle) -> String {
+        self.format_with(style, ParseLabels::get())
+    }
+}
+
+fn key<T: Serialize>(value: &T) -> String {
+    match serde_json::to_value(value) {
+        Ok(serde_json::Value::String(x)) => x,
+        _ => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(code: &str, style: ParseStyle, labels: &ParseLabels) -> String {
+        Declension::from_robinson(code)
+            .unwrap()
+            .format_with(style, labels)
+    }
+
+    #[test]
+    fn formats_a_verb() {
+        let labels = ParseLabels::english();
+        assert_eq!(
+            parse("V-AAI-3S", ParseStyle::Long, &labels),
+            "aorist active indicative 3rd person singular"
+        );
+        assert_eq!(
+            parse("V-AAI-3S", ParseStyle::Short, &labels),
+            "aor act ind 3s"
+        );
+        assert_eq!(
+            parse("V-PAP-GPM", ParseStyle::Short, &labels),
+            "pres act ptc gen pl masc"
+        );
+    }
+
+    #[test]
+    fn formats_a_noun() {
+        let labels = ParseLabels::english();
+        assert_eq!(
+            parse("N-GSM", ParseStyle::Long, &labels),
+            "common noun genitive singular masculine"
+        );
+        assert_eq!(parse("N-GSM", ParseStyle::Short, &labels), "n gen sg masc");
+    }
+
+    #[test]
+    fn overrides_labels_from_a_file() {
+        let path = std::env::temp_dir().join("parse_labels_test.json");
+        fs::write(
+            &path,
+            r#"{"long": {"aorist": "aoriste", "active": ""}, "short": {"third_singular": "3sg"}}"#,
+        )
+        .unwrap();
+
+        let mut labels = ParseLabels::english();
+        labels.extend(ParseLabels::from_file(path.to_str().unwrap()).unwrap());
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(
+            parse("V-AAI-3S", ParseStyle::Long, &labels),
+            "aoriste indicative 3rd person singular"
+        );
+        assert_eq!(
+            parse("V-AAI-3S", ParseStyle::Short, &labels),
+            "aor act ind 3sg"
+        );
+    }
+}
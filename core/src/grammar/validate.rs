@@ -0,0 +1,66 @@
+use super::{codes::CodeError, Declension, Mood, PartOfSpeech, Person, Pronoun, Tense};
+
+impl Declension {
+    /// Rejects combinations no Greek form can have, like a finite verb with a case or a first
+    /// person imperative. Missing fields are fine.
+    pub fn validate(&self) -> Result<(), CodeError> {
+        let impossible = |reason: &str| Err(CodeError::Impossible(format!("{reason} ({self})")));
+
+        if self.part_of_speech != PartOfSpeech::Verb {
+            if self.tense.is_some() || self.voice.is_some() || self.mood.is_some() {
+                return impossible("only verbs have a tense, voice or mood");
+            }
+            let is_personal = matches!(
+                self.part_of_speech,
+                PartOfSpeech::Pronoun(Pronoun::Personal | Pronoun::Reflexive)
+            );
+            if self.person.is_some() && !is_personal {
+                return impossible("only verbs and personal pronouns have a person");
+            }
+            let is_indeclinable = matches!(
+                self.part_of_speech,
+                PartOfSpeech::Adverb
+                    | PartOfSpeech::Preposition
+                    | PartOfSpeech::Particle
+                    | PartOfSpeech::Interjection
+            );
+            if is_indeclinable
+                && (self.case.is_some() || self.number.is_some() || self.gender.is_some())
+            {
+                return impossible("indeclinable words have no case, number or gender");
+            }
+            return Ok(());
+        }
+
+        let finite = !matches!(self.mood, None | Some(Mood::Infinitive | Mood::Participle));
+        if self.mood == Some(Mood::Infinitive)
+            && (self.person.is_some()
+                || self.number.is_some()
+                || self.case.is_some()
+                || self.gender.is_some())
+        {
+            return impossible("infinitives have no person, number, case or gender");
+        }
+        if self.mood == Some(Mood::Participle) && self.person.is_some() {
+            return impossible("participles have no person");
+        }
+        if finite && (self.case.is_some() || self.gender.is_some()) {
+            return impossible("finite verbs have no case or gender");
+        }
+        if self.mood == Some(Mood::Imperative) && self.person == Some(Person::First) {
+            return impossible("imperatives have no first person");
+        }
+        if matches!(self.tense, Some(Tense::Imperfect | Tense::Pluperfect))
+            && self.mood.is_some_and(|x| x != Mood::Indicative)
+        {
+            return impossible("the imperfect and pluperfect are only indicative");
+        }
+        if self.tense == Some(Tense::Future)
+            && matches!(self.mood, Some(Mood::Subjunctive | Mood::Imperative))
+        {
+            return impossible("the future has no subjunctive or imperative");
+        }
+
+        Ok(())
+    }
+}
@@ -1,2 +1,3 @@
+pub mod accent;
 pub mod noun;
 pub mod verb;
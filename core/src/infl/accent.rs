@@ -0,0 +1,443 @@
+use unicode_normalization::UnicodeNormalization;
+
+use crate::{
+    api::lexicon::lexicon_model::{NounInflectionCases, NounInflectionNumbers},
+    grammar::{Case, DeclensionType, Number},
+    utils::str::{
+        remove_diacritics::{remove_diacritics_with, Diacritic},
+        syllables::{syllables, Quantity, Syllable, Syllables},
+    },
+};
+
+pub mod clitic;
+
+const ACUTE: char = '\u{0301}';
+const GRAVE: char = '\u{0300}';
+const CIRCUMFLEX: char = '\u{0342}';
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Accent {
+    Acute,
+    Grave,
+    Circumflex,
+}
+
+impl Accent {
+    fn mark(self) -> char {
+        match self {
+            Accent::Acute => ACUTE,
+            Accent::Grave => GRAVE,
+            Accent::Circumflex => CIRCUMFLEX,
+        }
+    }
+}
+
+/// Where a form takes its accent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Accentuation {
+    /// As far from the end as the ultima allows, as finite verbs: λύομεν, ἔλυσα, λῦε.
+    Recessive,
+    /// On the penult: λελυκέναι, λυθῆναι, λελυμένος.
+    Penult,
+    /// On the first syllable of the ending, contracted from an ε or a stem vowel: λυθῶ,
+    /// λυθείην, τιθῶμεν.
+    Ending,
+    /// An acute on the ultima: λαβών, λυθείς.
+    Oxytone,
+    /// A circumflex on the ultima: λαβεῖν.
+    Perispomenon,
+}
+
+/// `word` without its accents, keeping breathings and the marks of vowel length.
+pub fn strip(word: &str) -> String {
+    remove_diacritics_with(
+        word,
+        &[
+            Diacritic::SmoothBreathing,
+            Diacritic::RoughBreathing,
+            Diacritic::IotaSubscript,
+            Diacritic::Diaeresis,
+            Diacritic::Macron,
+            Diacritic::Breve,
+        ],
+    )
+}
+
+/// Drops the macrons and breves only used to tell vowel length, once the accent is placed.
+fn finish(word: &str) -> String {
+    word.nfd()
+        .filter(|x| !matches!(*x, '\u{0304}' | '\u{0306}'))
+        .nfc()
+        .collect()
+}
+
+/// Accent of `word` and the position of its syllable, counted from the end.
+fn find_accent(word: &str) -> Option<(usize, Accent)> {
+    syllables(word).iter().rev().enumerate().find_map(|(i, x)| {
+        let accent = x.text.nfd().find_map(|x| match x {
+            ACUTE | '\u{0341}' => Some(Accent::Acute),
+            GRAVE | '\u{0340}' => Some(Accent::Grave),
+            CIRCUMFLEX => Some(Accent::Circumflex),
+            _ => None,
+        })?;
+        Some((i, accent))
+    })
+}
+
+/// Whether a syllable counts as long for the accent. Final -αι and -οι count as short, except
+/// in the optative.
+fn is_long(syllable: &Syllable, is_final: bool, final_diphthong_long: bool) -> bool {
+    if is_final && !final_diphthong_long && is_short_diphthong(syllable) {
+        return false;
+    }
+    syllable.quantity == Quantity::Long
+}
+
+/// Whether a final syllable ends in -αι or -οι, without a consonant after it as in -αις.
+fn is_short_diphthong(syllable: &Syllable) -> bool {
+    matches!(syllable.nucleus.as_str(), "αι" | "οι") && syllable.text.ends_with('ι')
+}
+
+/// The accent a syllable takes where nothing else decides: a circumflex on a long penult before
+/// a short ultima, an acute anywhere else.
+fn natural(syllables: &[Syllable], position: usize, final_diphthong_long: bool) -> Accent {
+    let (Some(penult), Some(ultima)) = (syllables.penult(), syllables.ultima()) else {
+        return Accent::Acute;
+    };
+    if position != 1 {
+        return Accent::Acute;
+    }
+
+    let penult = is_long(penult, false, final_diphthong_long);
+    let ultima = is_long(ultima, true, final_diphthong_long);
+    match penult && !ultima {
+        true => Accent::Circumflex,
+        false => Accent::Acute,
+    }
+}
+
+/// Puts `accent` on the syllable at `position` from the end of an unaccented word.
+fn place_on(syllables: &[Syllable], position: usize, accent: Accent) -> String {
+    let target = syllables.len().saturating_sub(position + 1);
+
+    let word = syllables
+        .iter()
+        .enumerate()
+        .map(|(i, syllable)| match i == target {
+            false => syllable.text.clone(),
+            true => syllable
+                .text
+                .chars()
+                .enumerate()
+                .map(|(j, x)| match j == syllable.accent_index {
+                    false => x.to_string(),
+                    true => accented(x, accent),
+                })
+                .collect(),
+        })
+        .collect::<String>();
+
+    finish(&word)
+}
+
+/// `letter` with `accent`, after its breathing and before its iota subscript.
+fn accented(letter: char, accent: Accent) -> String {
+    let mut marks = letter.to_string().nfd().collect::<Vec<_>>();
+    let at = marks
+        .iter()
+        .position(|x| *x == '\u{0345}')
+        .unwrap_or(marks.len());
+    marks.insert(at, accent.mark());
+    marks.into_iter().nfc().collect()
+}
+
+/// Puts `accent` on the syllable at `position` from the end of `word`, replacing any other.
+pub fn place(word: &str, position: usize, accent: Accent) -> String {
+    place_on(&syllables(&strip(word)), position, accent)
+}
+
+/// Accents `word`, whose `ending` is known for [`Accentuation::Ending`]. Final -αι and -οι are
+/// long in the optative, which says so with `final_diphthong_long`.
+pub fn accentuate(
+    word: &str,
+    ending: &str,
+    accentuation: Accentuation,
+    final_diphthong_long: bool,
+) -> String {
+    let syllables = syllables(&strip(word));
+    let n = syllables.len();
+    let Some(last) = syllables.ultima() else {
+        return word.to_string();
+    };
+    let ultima_long = is_long(last, true, final_diphthong_long);
+
+    let position = match accentuation {
+        Accentuation::Recessive if n == 1 => {
+            let accent = match ultima_long {
+                true => Accent::Circumflex,
+                false => Accent::Acute,
+            };
+            return place_on(&syllables, 0, accent);
+        }
+        Accentuation::Recessive if ultima_long || syllables.antepenult().is_none() => 1,
+        Accentuation::Recessive => 2,
+        Accentuation::Penult => 1,
+        Accentuation::Ending => {
+            let position = self::syllables(ending).len().saturating_sub(1);
+            match position {
+                0 if ultima_long => return place_on(&syllables, 0, Accent::Circumflex),
+                x if ultima_long => x.min(1),
+                x => x.min(2),
+            }
+        }
+        Accentuation::Oxytone => return place_on(&syllables, 0, Accent::Acute),
+        Accentuation::Perispomenon => return place_on(&syllables, 0, Accent::Circumflex),
+    };
+
+    let position = position.min(n - 1);
+    let accent = natural(&syllables, position, final_diphthong_long);
+    place_on(&syllables, position, accent)
+}
+
+/// Accents a contracted form from its uncontracted `stem` and `ending`: the accent the
+/// uncontracted form takes recessively becomes a circumflex on the contracted syllable if it
+/// was on the stem's vowel (τιμάω → τιμῶ), an acute if it was on the ending's (τιμαόμεθα →
+/// τιμώμεθα), and stays where it was otherwise (ἐτίμαον → ἐτίμων).
+pub fn contracted(
+    stem: &str,
+    ending: &str,
+    contracted: &str,
+    final_diphthong_long: bool,
+) -> String {
+    let uncontracted = accentuate(
+        &format!("{stem}{ending}"),
+        ending,
+        Accentuation::Recessive,
+        final_diphthong_long,
+    );
+    let Some((position, _)) = find_accent(&uncontracted) else {
+        return contracted.to_string();
+    };
+
+    let syllables = syllables(&strip(contracted));
+    let merged = self::syllables(ending).len().saturating_sub(1);
+
+    if position == merged + 1 {
+        return place_on(&syllables, merged, Accent::Circumflex);
+    }
+    if position == merged {
+        return place_on(&syllables, merged, Accent::Acute);
+    }
+
+    let position = match position > merged {
+        true => position - 1,
+        false => position,
+    };
+    let accent = natural(&syllables, position, final_diphthong_long);
+    place_on(&syllables, position, accent)
+}
+
+/// Accents `word` on its syllable at `index` from the start, as the lemma it comes from, or as
+/// close to it as the ultima allows. The ultima counts as short if `ultima_short`, and the
+/// lemma's `accent` is kept on the penult where the length of a vowel isn't written (χώρα,
+/// σῶμα).
+pub fn persistent(word: &str, index: usize, accent: Accent, ultima_short: bool) -> String {
+    let syllables = syllables(&strip(word));
+    let n = syllables.len();
+    let Some(ultima) = syllables.ultima() else {
+        return word.to_string();
+    };
+
+    let ultima_long = !ultima_short && is_long(ultima, true, false);
+    let wanted = n - 1 - index.min(n - 1);
+    let position = match wanted {
+        x if x < 2 => x,
+        _ if !ultima_long && syllables.antepenult().is_some() => 2,
+        _ => 1,
+    };
+
+    let accent = match position {
+        0 if accent == Accent::Circumflex && ultima_long => Accent::Circumflex,
+        1 if !ultima_long => {
+            let ultima_known =
+                ultima_short || ultima.quantity == Quantity::Short || is_short_diphthong(ultima);
+            match syllables.penult().map(|x| x.quantity) {
+                Some(Quantity::Short) => Accent::Acute,
+                Some(Quantity::Long) if ultima_known => Accent::Circumflex,
+                _ if accent == Accent::Grave => Accent::Acute,
+                _ => accent,
+            }
+        }
+        _ => Accent::Acute,
+    };
+    place_on(&syllables, position, accent)
+}
+
+/// `word` accented persistently like `lemma`, another form of the same word: λύουσα and λῦον
+/// like λύων. The ultima counts as short if `ultima_short`.
+pub fn like(word: &str, lemma: &str, ultima_short: bool) -> String {
+    match lemma_accent(lemma) {
+        Some((index, _, accent)) => persistent(word, index, accent, ultima_short),
+        None => word.to_string(),
+    }
+}
+
+/// Accent of `lemma` as the index of its syllable from the start.
+fn lemma_accent(lemma: &str) -> Option<(usize, usize, Accent)> {
+    let count = syllables(lemma).len();
+    let (position, accent) = find_accent(lemma)?;
+    Some((count - 1 - position, count, accent))
+}
+
+/// Accents the declined forms of `lemma`, persistently: each keeps the accent of the lemma as
+/// far as it can. Oxytones of the 1st and 2nd declensions take a circumflex in the genitive and
+/// dative (θεοῦ, τιμῇ), the 1st declension always does in the genitive plural (χωρῶν), 3rd
+/// declension monosyllables accent the ending there (νυκτός, νυξί), and ευ stems take a
+/// circumflex on the endings contracted with their ε (βασιλεῖ, βασιλεῖς). σ stems, told by
+/// their genitive in -ους, do so on the contracted genitive plural and genitive and dative dual
+/// (γενῶν, γενοῖν). Forms of an unaccented lemma are left as they are.
+pub fn accent_noun(
+    numbers: &mut NounInflectionNumbers,
+    lemma: &str,
+    decl_type: Option<DeclensionType>,
+) {
+    let Some((index, count, accent)) = lemma_accent(lemma) else {
+        return;
+    };
+    let oxytone = index == count - 1;
+    let eu_stem = strip(lemma).ends_with("ευς");
+    let sigma_stem = decl_type == Some(DeclensionType::Third)
+        && numbers
+            .singular
+            .iter()
+            .flat_map(|x| x.genitive.iter().flatten())
+            .filter_map(|x| x.contracted.as_deref())
+            .any(|x| strip(x).ends_with("ους"));
+
+    let NounInflectionNumbers {
+        singular,
+        dual,
+        plural,
+    } = numbers;
+    let numbers = [
+        (singular, Number::Singular),
+        (dual, Number::Dual),
+        (plural, Number::Plural),
+    ];
+
+    for (cases, number) in numbers {
+        let is_plural = number == Number::Plural;
+        let Some(cases) = cases else { continue };
+        let NounInflectionCases {
+            nominative,
+            genitive,
+            dative,
+            accusative,
+            vocative,
+        } = cases;
+        let cases = [
+            (nominative, Case::Nominative),
+            (genitive, Case::Genitive),
+            (dative, Case::Dative),
+            (accusative, Case::Accusative),
+            (vocative, Case::Vocative),
+        ];
+
+        for (forms, case) in cases {
+            let oblique = matches!(case, Case::Genitive | Case::Dative);
+            let is_lemma = number == Number::Singular && case == Case::Nominative;
+            for form in forms.iter_mut().flatten() {
+                let Some(word) = &form.contracted else {
+                    continue;
+                };
+                let bare = strip(word);
+                let ultima_long = syllables(&bare)
+                    .ultima()
+                    .is_some_and(|x| is_long(x, true, false));
+
+                let accented = match decl_type {
+                    Some(DeclensionType::First) if is_plural && case == Case::Genitive => {
+                        place(&bare, 0, Accent::Circumflex)
+                    }
+                    Some(DeclensionType::First | DeclensionType::Second) if oxytone && oblique => {
+                        place(&bare, 0, Accent::Circumflex)
+                    }
+                    Some(DeclensionType::Third)
+                        if sigma_stem
+                            && ((is_plural && case == Case::Genitive)
+                                || (number == Number::Dual && oblique)) =>
+                    {
+                        place(&bare, 0, Accent::Circumflex)
+                    }
+                    Some(DeclensionType::Third) if count == 1 && oblique => {
+                        let accent = match ultima_long {
+                            true => Accent::Circumflex,
+                            false => Accent::Acute,
+                        };
+                        place(&bare, 0, accent)
+                    }
+                    // πόλεως keeps the accent of πόλις on its antepenult, and the ι of the
+                    // dative plural is short (σώμασι, βασιλεῦσι).
+                    Some(DeclensionType::Third) => {
+                        let metathesis = bare.ends_with("εως") || bare.ends_with("εων");
+                        let dative_plural = is_plural && case == Case::Dative;
+                        let accented =
+                            persistent(&bare, index, accent, metathesis || dative_plural);
+                        let on_ultima = find_accent(&accented).is_some_and(|(i, _)| i == 0);
+                        match eu_stem && !is_lemma && ultima_long && on_ultima {
+                            true => place(&bare, 0, Accent::Circumflex),
+                            false => accented,
+                        }
+                    }
+                    _ => persistent(&bare, index, accent, false),
+                };
+                form.contracted = Some(accented);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accents_recessively() {
+        let recessive = |x| accentuate(x, "", Accentuation::Recessive, false);
+        assert_eq!(recessive("λυομεν"), "λύομεν");
+        assert_eq!(recessive("λυε"), "λύε");
+        assert_eq!(recessive("ἐλυσα"), "ἔλυσα");
+        assert_eq!(recessive("παιδευοι"), "παίδευοι");
+        assert_eq!(
+            accentuate("παιδευοι", "", Accentuation::Recessive, true),
+            "παιδεύοι"
+        );
+    }
+
+    #[test]
+    fn accents_the_penult_by_its_length() {
+        let penult = |x| accentuate(x, "", Accentuation::Penult, false);
+        assert_eq!(penult("λελυκεναι"), "λελυκέναι");
+        assert_eq!(penult("λυθηναι"), "λυθῆναι");
+        assert_eq!(penult("λῡσαι"), "λῦσαι");
+        assert_eq!(penult("παιδευσαι"), "παιδεῦσαι");
+    }
+
+    #[test]
+    fn keeps_the_lemma_accent_where_length_is_unwritten() {
+        assert_eq!(persistent("χωρα", 0, Accent::Acute, false), "χώρα");
+        assert_eq!(persistent("γλωσσα", 0, Accent::Circumflex, false), "γλῶσσα");
+        assert_eq!(persistent("χωραι", 0, Accent::Acute, false), "χῶραι");
+        assert_eq!(persistent("ανθρωπου", 0, Accent::Acute, false), "ανθρώπου");
+        assert_eq!(
+            persistent("σωματος", 0, Accent::Circumflex, false),
+            "σώματος"
+        );
+    }
+
+    #[test]
+    fn accents_like_another_form() {
+        assert_eq!(like("λυουσα", "λύων", true), "λύουσα");
+        assert_eq!(like("παιδευον", "παιδεύων", false), "παιδεῦον");
+    }
+}
@@ -0,0 +1,168 @@
+use unicode_normalization::UnicodeNormalization;
+
+use super::{find_accent, place, strip, syllables, Accent};
+
+/// Enclitics, as written without their accent.
+const ENCLITICS: [&str; 34] = [
+    "τις",
+    "τι",
+    "τινος",
+    "του",
+    "τινι",
+    "τῳ",
+    "τινα",
+    "τινε",
+    "τινοιν",
+    "τινες",
+    "τινων",
+    "τισι",
+    "τισιν",
+    "τινας",
+    "που",
+    "ποι",
+    "ποθεν",
+    "ποτε",
+    "πως",
+    "πω",
+    "γε",
+    "τε",
+    "τοι",
+    "περ",
+    "μου",
+    "μοι",
+    "με",
+    "σου",
+    "σοι",
+    "σε",
+    "ἐστι",
+    "ἐστιν",
+    "φησι",
+    "φησιν",
+];
+
+/// Proclitics, which have no accent of their own.
+const PROCLITICS: [&str; 14] = [
+    "ὁ", "ἡ", "οἱ", "αἱ", "ἐν", "εἰς", "ἐς", "ἐκ", "ἐξ", "εἰ", "ὡς", "οὐ", "οὐκ", "οὐχ",
+];
+
+/// Whether `word` is written as an enclitic: unaccented, or with its accent on the ultima of a
+/// disyllable (τινός, ἐστί). Accented monosyllables are the article or an interrogative (τοῦ, τῷ,
+/// τίς, ποῦ), which keep their accent.
+fn is_enclitic(word: &str) -> bool {
+    let written = match find_accent(word) {
+        None => true,
+        Some((0, _)) => syllables(word).len() == 2,
+        Some(_) => false,
+    };
+    written && ENCLITICS.contains(&strip(word).to_lowercase().as_str())
+}
+
+fn is_proclitic(word: &str) -> bool {
+    find_accent(word).is_none() && PROCLITICS.contains(&strip(word).to_lowercase().as_str())
+}
+
+fn ends_clause(word: &str) -> bool {
+    word.ends_with([',', '.', ';', '·', '!', ':'])
+}
+
+/// Accents the words of a phrase as they sit together. An enclitic gives its accent to the word
+/// before it, as an acute on the ultima of a proclitic (εἴ τις), a proparoxytone (ἄνθρωπός τις)
+/// or a properispomenon (δῶρόν τι), or keeps it after a paroxytone when it has two syllables
+/// (λόγος τινός). An oxytone followed by another word in the same clause takes a grave.
+#[allow(dead_code)]
+pub fn accent_phrase(words: &[&str]) -> Vec<String> {
+    let mut accented = words.iter().map(|x| x.to_string()).collect::<Vec<_>>();
+
+    for i in 0..accented.len() {
+        if i > 0 && is_enclitic(words[i]) && !ends_clause(words[i - 1]) {
+            let host = accented[i - 1].clone();
+            let enclitic = strip(words[i]);
+
+            match find_accent(&host) {
+                None if is_proclitic(words[i - 1]) || is_enclitic(words[i - 1]) => {
+                    accented[i - 1] = place(&host, 0, Accent::Acute);
+                    accented[i] = enclitic;
+                }
+                Some((2, _)) | Some((1, Accent::Circumflex)) => {
+                    accented[i - 1] = add_acute_on_ultima(&host);
+                    accented[i] = enclitic;
+                }
+                Some((1, Accent::Acute)) if syllables(&enclitic).len() > 1 => {
+                    let accent = match enclitic.ends_with("ων") || enclitic.ends_with("οιν") {
+                        true => Accent::Circumflex,
+                        false => Accent::Acute,
+                    };
+                    accented[i] = place(&enclitic, 0, accent);
+                }
+                Some((0, Accent::Grave)) => {
+                    accented[i - 1] = place(&host, 0, Accent::Acute);
+                    accented[i] = enclitic;
+                }
+                _ => accented[i] = enclitic,
+            }
+        }
+
+        let followed = words.get(i + 1).is_some_and(|x| !is_enclitic(x));
+        let interrogative =
+            find_accent(words[i]).is_some() && matches!(strip(words[i]).as_str(), "τις" | "τι");
+        if followed && !ends_clause(words[i]) && !interrogative {
+            if let Some((0, Accent::Acute)) = find_accent(&accented[i]) {
+                accented[i] = place(&accented[i], 0, Accent::Grave);
+            }
+        }
+    }
+
+    accented
+}
+
+/// `word` with a second accent, an acute on its ultima, as an enclitic gives it.
+fn add_acute_on_ultima(word: &str) -> String {
+    // The syllables are split from the composed word, which their text is cut from.
+    let word = word.nfc().collect::<String>();
+    let Some(ultima) = syllables(&word).pop() else {
+        return word;
+    };
+    let (head, _) = word.split_at(word.len() - ultima.text.len());
+    format!("{head}{}", place(&ultima.text, 0, Accent::Acute))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn phrase(words: &str) -> String {
+        accent_phrase(&words.split(' ').collect::<Vec<_>>()).join(" ")
+    }
+
+    #[test]
+    fn enclitics_lean_on_the_word_before() {
+        assert_eq!(phrase("εἰ τις"), "εἴ τις");
+        assert_eq!(phrase("ἄνθρωπος τις"), "ἄνθρωπός τις");
+        assert_eq!(phrase("δῶρον τι"), "δῶρόν τι");
+        assert_eq!(phrase("λόγος τινός"), "λόγος τινός");
+        assert_eq!(phrase("λόγος τις"), "λόγος τις");
+    }
+
+    #[test]
+    fn accented_homographs_are_not_enclitics() {
+        assert_eq!(phrase("ἐν τῷ"), "ἐν τῷ");
+        assert_eq!(phrase("ἐκ τοῦ λόγου"), "ἐκ τοῦ λόγου");
+        assert_eq!(phrase("τίς λόγος"), "τίς λόγος");
+        assert_eq!(phrase("ἄνθρωπος τίς"), "ἄνθρωπος τίς");
+        assert_eq!(phrase("ποῦ ἐστιν"), "ποῦ ἐστιν");
+        assert_eq!(phrase("ἄνθρωπος ἐστί"), "ἄνθρωπός ἐστι");
+    }
+
+    #[test]
+    fn oxytones_take_a_grave_before_another_word() {
+        assert_eq!(phrase("καλός λόγος"), "καλὸς λόγος");
+        assert_eq!(phrase("λόγος καλός"), "λόγος καλός");
+        assert_eq!(phrase("καλός, λόγος"), "καλός, λόγος");
+    }
+
+    #[test]
+    fn adds_an_acute_to_decomposed_words() {
+        let decomposed = "ἄνθρωπος".nfd().collect::<String>();
+        assert_eq!(add_acute_on_ultima(&decomposed), "ἄνθρωπός");
+    }
+}
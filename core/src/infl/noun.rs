@@ -4,11 +4,55 @@ use crate::{
     },
     error::SafeError,
     grammar::{Declension, DeclensionType, Gender},
+    infl::accent,
+    utils::str::{
+        remove_diacritics::remove_diacritics,
+        syllables::{vowel_quantity, Quantity},
+    },
 };
 
-pub fn inflect(lemma: &str, declension: &Declension) -> Result<NounInflectionGenders, SafeError> {
-    let numbers = match declension.decl_type {
-        Some(DeclensionType::First) => inflect_1st(lemma, declension)?,
+mod first;
+mod second;
+mod third;
+
+/// Endings in case order: nominative, genitive, dative, accusative, vocative. Alternatives are
+/// separated by `|`, like the movable ν of `σι|σιν`.
+pub(super) type Endings = [&'static str; 5];
+
+/// Stem taken by short endings, usually from the lemma, and by long ones, from the genitive
+/// singular, as the 3rd declension needs.
+pub(super) struct Stems<'a> {
+    pub short: &'a str,
+    pub long: &'a str,
+}
+
+/// Declines `lemma` from its genitive singular, which the 2nd declension can do without and
+/// the 3rd needs to find its stem.
+pub fn inflect(
+    lemma: &str,
+    genitive: Option<&str>,
+    declension: &Declension,
+) -> Result<NounInflectionGenders, SafeError> {
+    let gender = declension
+        .gender
+        .ok_or_else(|| format!("no gender given for {lemma}"))?;
+    let decl_type = declension
+        .decl_type
+        .or_else(|| guess_declension_type(lemma, genitive));
+
+    // Forms are generated without accents, then accented from the lemma's.
+    let bare = accent::strip(lemma);
+    let genitive = genitive.map(accent::strip);
+    let genitive = genitive.as_deref();
+
+    let mut numbers = match decl_type {
+        Some(DeclensionType::First) => first::inflect(&bare, gender)?,
+        Some(DeclensionType::Second) => second::inflect(&bare, genitive, gender)?,
+        Some(DeclensionType::Third) => {
+            let genitive = genitive
+                .ok_or_else(|| format!("the 3rd declension needs a genitive for {lemma}"))?;
+            third::inflect(&bare, genitive, gender)?
+        }
         _ => {
             return Err(format!(
                 "could not match declension type for {lemma}: {:?}",
@@ -18,325 +62,225 @@ pub fn inflect(lemma: &str, declension: &Declension) -> Result<NounInflectionGen
         }
     };
 
-    let genders = match declension.gender {
-        Some(Gender::Feminine) => NounInflectionGenders {
+    accent::accent_noun(&mut numbers, lemma, decl_type);
+
+    let genders = match gender {
+        Gender::Feminine => NounInflectionGenders {
             feminine: Some(*numbers),
             ..Default::default()
         },
-        Some(Gender::Masculine) => NounInflectionGenders {
+        Gender::Masculine => NounInflectionGenders {
             masculine: Some(*numbers),
             ..Default::default()
         },
-        Some(Gender::Neuter) => NounInflectionGenders {
+        Gender::Neuter => NounInflectionGenders {
             neuter: Some(*numbers),
             ..Default::default()
         },
-        None => return Err(format!("could not gendr for {lemma}").into()),
     };
 
     Ok(genders)
 }
 
-fn inflect_1st(
-    lemma: &str,
-    declension: &Declension,
-) -> Result<Box<NounInflectionNumbers>, SafeError> {
-    match declension.gender {
-        Some(Gender::Feminine) => inflect_1st_fem(lemma),
-        Some(Gender::Masculine) => inflect_1st_mas(lemma),
-        Some(Gender::Neuter) => {
-            Err(format!("1st decl is not supposed to have neuter, for {lemma}").into())
+fn guess_declension_type(lemma: &str, genitive: Option<&str>) -> Option<DeclensionType> {
+    let lemma = remove_diacritics(lemma);
+    let genitive = remove_diacritics(genitive?);
+
+    match (lemma.as_str(), genitive.as_str()) {
+        (l, g) if (l.ends_with("ος") || l.ends_with("ον")) && g.ends_with("ου") => {
+            Some(DeclensionType::Second)
         }
-        None => todo!(),
+        (l, g)
+            if (l.ends_with('η') || l.ends_with('α'))
+                && (g.ends_with("ης") || g.ends_with("ας")) =>
+        {
+            Some(DeclensionType::First)
+        }
+        (l, g) if (l.ends_with("ης") || l.ends_with("ας")) && g.ends_with("ου") => {
+            Some(DeclensionType::First)
+        }
+        _ => Some(DeclensionType::Third),
     }
 }
 
-fn inflect_1st_fem(lemma: &str) -> Result<Box<NounInflectionNumbers>, SafeError> {
-    match lemma.chars().last() {
-        Some('η') => Ok(conjugate(
-            lemma.trim_end_matches('η'),
-            &get_1st_fem_h_endings(),
-        )),
-        Some('ᾱ') => Ok(conjugate(
-            lemma.trim_end_matches('ᾱ'),
-            &get_1st_fem_a_macron_endings(),
-        )),
-        Some('ᾰ') => Ok(conjugate(
-            lemma.trim_end_matches('ᾰ'),
-            &get_1st_fem_a_breve_endings(),
-        )),
-        Some('α') => Ok(conjugate(
-            lemma.trim_end_matches('α'),
-            &get_1st_fem_a_breve_endings(),
-        )),
-        _ => Err(format!("could not match lemma {lemma}").into()),
-    }
+/// `word` without `ending`, compared without diacritics, if a stem is left.
+fn strip_ending<'a>(word: &'a str, ending: &str) -> Option<&'a str> {
+    let count = ending.chars().count();
+    let split = word.char_indices().rev().nth(count - 1)?.0;
+    let (stem, rest) = word.split_at(split);
+
+    (!stem.is_empty() && remove_diacritics(rest) == remove_diacritics(ending)).then_some(stem)
 }
 
-fn inflect_1st_mas(lemma: &str) -> Result<Box<NounInflectionNumbers>, SafeError> {
-    match lemma {
-        x if x.ends_with("ης") => Ok(conjugate(
-            lemma.trim_end_matches("ης"),
-            &get_1st_mas_hs_endings(),
-        )),
-        x if x.ends_with("ᾱς") => Ok(conjugate(
-            lemma.trim_end_matches("ᾱς"),
-            &get_1st_mas_as_endings(),
-        )),
-        x if x.ends_with("ας") => Ok(conjugate(
-            lemma.trim_end_matches("ας"),
-            &get_1st_mas_as_endings(),
-        )),
-        _ => Err(format!("could not match lemma {lemma}").into()),
+/// Whether the syllable of an ending is long, which decides the stem it takes.
+fn is_long(ending: &str) -> bool {
+    let base = remove_diacritics(ending);
+    if base == "οι" || base == "αι" {
+        return false;
     }
+
+    ending
+        .chars()
+        .any(|x| vowel_quantity(x) == Some(Quantity::Long))
+        || ["αι", "ει", "οι", "υι", "αυ", "ευ", "ου", "ηυ"]
+            .iter()
+            .any(|x| base.contains(x))
 }
 
-fn conjugate_inflection_forms(root: &str, forms: &[InflectionForm]) -> Vec<InflectionForm> {
-    forms
-        .iter()
+fn forms(stem: &str, endings: &str) -> Vec<InflectionForm> {
+    endings
+        .split('|')
         .map(|ending| InflectionForm {
-            contracted: Some(format!("{}{}", root, ending.contracted.as_ref().unwrap())),
-            uncontracted: Some(vec![
-                root.to_string(),
-                ending.contracted.as_ref().unwrap().to_string(),
-            ]),
+            contracted: Some(format!("{stem}{ending}")),
+            uncontracted: Some(vec![stem.to_string(), ending.to_string()]),
         })
         .collect()
 }
 
-fn conjugate_inflection_cases(root: &str, cases: &NounInflectionCases) -> NounInflectionCases {
+fn lemma_form(lemma: &str) -> Option<Vec<InflectionForm>> {
+    Some(vec![InflectionForm {
+        contracted: Some(lemma.to_string()),
+        uncontracted: Some(vec![lemma.to_string()]),
+    }])
+}
+
+fn decline_cases(stems: &Stems, endings: Endings) -> NounInflectionCases {
+    let [nominative, genitive, dative, accusative, vocative] = endings.map(|x| {
+        let stem = if is_long(x) { stems.long } else { stems.short };
+        Some(forms(stem, x))
+    });
+
     NounInflectionCases {
-        nominative: Some(conjugate_inflection_forms(
-            root,
-            cases.nominative.as_ref().unwrap(),
-        )),
-        vocative: Some(conjugate_inflection_forms(
-            root,
-            cases.vocative.as_ref().unwrap(),
-        )),
-        accusative: Some(conjugate_inflection_forms(
-            root,
-            cases.accusative.as_ref().unwrap(),
-        )),
-        genitive: Some(conjugate_inflection_forms(
-            root,
-            cases.genitive.as_ref().unwrap(),
-        )),
-        dative: Some(conjugate_inflection_forms(
-            root,
-            cases.dative.as_ref().unwrap(),
-        )),
+        nominative,
+        genitive,
+        dative,
+        accusative,
+        vocative,
     }
 }
 
-fn conjugate(root: &str, endings: &NounInflectionNumbers) -> Box<NounInflectionNumbers> {
+pub(super) fn decline(
+    stems: &Stems,
+    singular: Endings,
+    dual: Endings,
+    plural: Endings,
+) -> Box<NounInflectionNumbers> {
     Box::from(NounInflectionNumbers {
-        singular: Some(conjugate_inflection_cases(
-            root,
-            endings.singular.as_ref().unwrap(),
-        )),
-        dual: Some(conjugate_inflection_cases(
-            root,
-            endings.dual.as_ref().unwrap(),
-        )),
-        plural: Some(conjugate_inflection_cases(
-            root,
-            endings.plural.as_ref().unwrap(),
-        )),
+        singular: Some(decline_cases(stems, singular)),
+        dual: Some(decline_cases(stems, dual)),
+        plural: Some(decline_cases(stems, plural)),
     })
 }
 
-fn get_1st_eta_du_endings() -> Box<NounInflectionCases> {
-    Box::from(NounInflectionCases {
-        nominative: Some(vec![InflectionForm {
-            contracted: Some("ᾱ".to_string()),
-            ..Default::default()
-        }]),
-        vocative: Some(vec![InflectionForm {
-            contracted: Some("ᾱ".to_string()),
-            ..Default::default()
-        }]),
-        accusative: Some(vec![InflectionForm {
-            contracted: Some("ᾱ".to_string()),
-            ..Default::default()
-        }]),
-        genitive: Some(vec![InflectionForm {
-            contracted: Some("αιν".to_string()),
-            ..Default::default()
-        }]),
-        dative: Some(vec![InflectionForm {
-            contracted: Some("αιν".to_string()),
-            ..Default::default()
-        }]),
-    })
-}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grammar::{Noun, PartOfSpeech};
 
-fn get_1st_eta_pl_endings() -> Box<NounInflectionCases> {
-    Box::from(NounInflectionCases {
-        nominative: Some(vec![InflectionForm {
-            contracted: Some("αι".to_string()),
-            ..Default::default()
-        }]),
-        vocative: Some(vec![InflectionForm {
-            contracted: Some("αι".to_string()),
-            ..Default::default()
-        }]),
-        accusative: Some(vec![InflectionForm {
-            contracted: Some("ᾱς".to_string()),
-            ..Default::default()
-        }]),
-        genitive: Some(vec![InflectionForm {
-            contracted: Some("ων".to_string()),
-            ..Default::default()
-        }]),
-        dative: Some(vec![InflectionForm {
-            contracted: Some("αις".to_string()),
-            ..Default::default()
-        }]),
-    })
-}
+    /// The forms of the only gender declined, by number then case, alternatives joined by `|`.
+    fn paradigm(lemma: &str, genitive: &str, gender: Gender) -> String {
+        let declension = Declension {
+            gender: Some(gender),
+            ..Declension::partial_default(PartOfSpeech::Noun(Noun::Common))
+        };
+        let genders = inflect(lemma, Some(genitive), &declension).unwrap();
+        let numbers = [genders.masculine, genders.feminine, genders.neuter]
+            .into_iter()
+            .flatten()
+            .next()
+            .unwrap();
 
-fn get_1st_fem_h_endings() -> Box<NounInflectionNumbers> {
-    Box::from(NounInflectionNumbers {
-        singular: Some(NounInflectionCases {
-            nominative: Some(vec![InflectionForm {
-                contracted: Some("η".to_string()),
-                ..Default::default()
-            }]),
-            vocative: Some(vec![InflectionForm {
-                contracted: Some("ης".to_string()),
-                ..Default::default()
-            }]),
-            accusative: Some(vec![InflectionForm {
-                contracted: Some("ην".to_string()),
-                ..Default::default()
-            }]),
-            genitive: Some(vec![InflectionForm {
-                contracted: Some("ης".to_string()),
-                ..Default::default()
-            }]),
-            dative: Some(vec![InflectionForm {
-                contracted: Some("ῃ".to_string()),
-                ..Default::default()
-            }]),
-        }),
-        dual: Some(*get_1st_eta_du_endings()),
-        plural: Some(*get_1st_eta_pl_endings()),
-    })
-}
+        [numbers.singular, numbers.dual, numbers.plural]
+            .into_iter()
+            .flatten()
+            .flat_map(|x| [x.nominative, x.genitive, x.dative, x.accusative, x.vocative])
+            .map(|x| {
+                x.unwrap()
+                    .into_iter()
+                    .filter_map(|x| x.contracted)
+                    .collect::<Vec<_>>()
+                    .join("|")
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
 
-fn get_1st_fem_a_breve_endings() -> Box<NounInflectionNumbers> {
-    Box::from(NounInflectionNumbers {
-        singular: Some(NounInflectionCases {
-            nominative: Some(vec![InflectionForm {
-                contracted: Some("ᾰ".to_string()),
-                ..Default::default()
-            }]),
-            vocative: Some(vec![InflectionForm {
-                contracted: Some("ᾰ".to_string()),
-                ..Default::default()
-            }]),
-            accusative: Some(vec![InflectionForm {
-                contracted: Some("ᾰν".to_string()),
-                ..Default::default()
-            }]),
-            genitive: Some(vec![InflectionForm {
-                contracted: Some("ᾱς".to_string()),
-                ..Default::default()
-            }]),
-            dative: Some(vec![InflectionForm {
-                contracted: Some("ᾳ".to_string()),
-                ..Default::default()
-            }]),
-        }),
-        dual: Some(*get_1st_eta_du_endings()),
-        plural: Some(*get_1st_eta_pl_endings()),
-    })
-}
+    #[test]
+    fn declines_1st() {
+        assert_eq!(
+            paradigm("τιμή", "τιμῆς", Gender::Feminine),
+            "τιμή τιμῆς τιμῇ τιμήν τιμή τιμά τιμαῖν τιμαῖν τιμά τιμά \
+             τιμαί τιμῶν τιμαῖς τιμάς τιμαί"
+        );
+        assert_eq!(
+            paradigm("χώρα", "χώρας", Gender::Feminine),
+            "χώρα χώρας χώρᾳ χώραν χώρα χώρα χώραιν χώραιν χώρα χώρα \
+             χῶραι χωρῶν χώραις χώρας χῶραι"
+        );
+        assert_eq!(
+            paradigm("θάλασσα", "θαλάσσης", Gender::Feminine),
+            "θάλασσα θαλάσσης θαλάσσῃ θάλασσαν θάλασσα θαλάσσα θαλάσσαιν θαλάσσαιν θαλάσσα \
+             θαλάσσα θάλασσαι θαλασσῶν θαλάσσαις θαλάσσας θάλασσαι"
+        );
+        assert_eq!(
+            paradigm("κριτής", "κριτοῦ", Gender::Masculine),
+            "κριτής κριτοῦ κριτῇ κριτήν κριτά κριτά κριταῖν κριταῖν κριτά κριτά \
+             κριταί κριτῶν κριταῖς κριτάς κριταί"
+        );
+        assert_eq!(
+            paradigm("νεανίας", "νεανίου", Gender::Masculine),
+            "νεανίας νεανίου νεανίᾳ νεανίαν νεανία νεανία νεανίαιν νεανίαιν νεανία νεανία \
+             νεανίαι νεανιῶν νεανίαις νεανίας νεανίαι"
+        );
+    }
 
-fn get_1st_fem_a_macron_endings() -> Box<NounInflectionNumbers> {
-    Box::from(NounInflectionNumbers {
-        singular: Some(NounInflectionCases {
-            nominative: Some(vec![InflectionForm {
-                contracted: Some("ᾱ".to_string()),
-                ..Default::default()
-            }]),
-            vocative: Some(vec![InflectionForm {
-                contracted: Some("ᾱ".to_string()),
-                ..Default::default()
-            }]),
-            accusative: Some(vec![InflectionForm {
-                contracted: Some("ᾱν".to_string()),
-                ..Default::default()
-            }]),
-            genitive: Some(vec![InflectionForm {
-                contracted: Some("ᾱς".to_string()),
-                ..Default::default()
-            }]),
-            dative: Some(vec![InflectionForm {
-                contracted: Some("ᾳ".to_string()),
-                ..Default::default()
-            }]),
-        }),
-        dual: Some(*get_1st_eta_du_endings()),
-        plural: Some(*get_1st_eta_pl_endings()),
-    })
-}
+    #[test]
+    fn declines_2nd() {
+        assert_eq!(
+            paradigm("λόγος", "λόγου", Gender::Masculine),
+            "λόγος λόγου λόγῳ λόγον λόγε λόγω λόγοιν λόγοιν λόγω λόγω \
+             λόγοι λόγων λόγοις λόγους λόγοι"
+        );
+    }
 
-fn get_1st_mas_hs_endings() -> Box<NounInflectionNumbers> {
-    Box::from(NounInflectionNumbers {
-        singular: Some(NounInflectionCases {
-            nominative: Some(vec![InflectionForm {
-                contracted: Some("ης".to_string()),
-                ..Default::default()
-            }]),
-            vocative: Some(vec![InflectionForm {
-                contracted: Some("η".to_string()),
-                ..Default::default()
-            }]),
-            accusative: Some(vec![InflectionForm {
-                contracted: Some("ην".to_string()),
-                ..Default::default()
-            }]),
-            genitive: Some(vec![InflectionForm {
-                contracted: Some("ου".to_string()),
-                ..Default::default()
-            }]),
-            dative: Some(vec![InflectionForm {
-                contracted: Some("ῃ".to_string()),
-                ..Default::default()
-            }]),
-        }),
-        dual: Some(*get_1st_eta_du_endings()),
-        plural: Some(*get_1st_eta_pl_endings()),
-    })
-}
+    #[test]
+    fn declines_3rd() {
+        assert_eq!(
+            paradigm("πόλις", "πόλεως", Gender::Feminine),
+            "πόλις πόλεως πόλει πόλιν πόλι πόλει πολέοιν πολέοιν πόλει πόλει \
+             πόλεις πόλεων πόλεσι|πόλεσιν πόλεις πόλεις"
+        );
+        assert_eq!(
+            paradigm("φύλαξ", "φύλακος", Gender::Masculine),
+            "φύλαξ φύλακος φύλακι φύλακα φύλαξ φύλακε φυλάκοιν φυλάκοιν φύλακε φύλακε \
+             φύλακες φυλάκων φύλαξι|φύλαξιν φύλακας φύλακες"
+        );
+        assert_eq!(
+            paradigm("βασιλεύς", "βασιλέως", Gender::Masculine),
+            "βασιλεύς βασιλέως βασιλεῖ βασιλέα βασιλεῦ βασιλῆ βασιλέοιν βασιλέοιν βασιλῆ βασιλῆ \
+             βασιλεῖς βασιλέων βασιλεῦσι|βασιλεῦσιν βασιλέας βασιλεῖς"
+        );
+        assert_eq!(
+            paradigm("σῶμα", "σώματος", Gender::Neuter),
+            "σῶμα σώματος σώματι σῶμα σῶμα σώματε σωμάτοιν σωμάτοιν σώματε σώματε \
+             σώματα σωμάτων σώμασι|σώμασιν σώματα σώματα"
+        );
+        assert_eq!(
+            paradigm("γένος", "γένους", Gender::Neuter),
+            "γένος γένους γένει γένος γένος γένει γενοῖν γενοῖν γένει γένει \
+             γένη γενῶν γένεσι|γένεσιν γένη γένη"
+        );
+    }
 
-fn get_1st_mas_as_endings() -> Box<NounInflectionNumbers> {
-    Box::from(NounInflectionNumbers {
-        singular: Some(NounInflectionCases {
-            nominative: Some(vec![InflectionForm {
-                contracted: Some("ᾱς".to_string()),
-                ..Default::default()
-            }]),
-            vocative: Some(vec![InflectionForm {
-                contracted: Some("ᾱ".to_string()),
-                ..Default::default()
-            }]),
-            accusative: Some(vec![InflectionForm {
-                contracted: Some("ᾱν".to_string()),
-                ..Default::default()
-            }]),
-            genitive: Some(vec![InflectionForm {
-                contracted: Some("ου".to_string()),
-                ..Default::default()
-            }]),
-            dative: Some(vec![InflectionForm {
-                contracted: Some("ᾳ".to_string()),
-                ..Default::default()
-            }]),
-        }),
-        dual: Some(*get_1st_eta_du_endings()),
-        plural: Some(*get_1st_eta_pl_endings()),
-    })
+    #[test]
+    fn rejects_unmodelled_stems() {
+        let declension = |gender| Declension {
+            gender: Some(gender),
+            decl_type: Some(DeclensionType::Third),
+            ..Declension::partial_default(PartOfSpeech::Noun(Noun::Common))
+        };
+
+        assert!(inflect("πατήρ", Some("πατρός"), &declension(Gender::Masculine)).is_err());
+        assert!(inflect("ς", Some("ς"), &declension(Gender::Masculine)).is_err());
+    }
 }
@@ -0,0 +1,53 @@
+use crate::{
+    api::lexicon::lexicon_model::NounInflectionNumbers, error::SafeError, grammar::Gender,
+};
+
+use super::{decline, strip_ending, Endings, Stems};
+
+const DUAL: Endings = ["ᾱ", "αιν", "αιν", "ᾱ", "ᾱ"];
+const PLURAL: Endings = ["αι", "ων", "αις", "ᾱς", "αι"];
+
+/// -η feminines like τιμή, -α ones like χώρα and θάλασσα, and -ης and -ας masculines like
+/// κριτής and νεανίας. An α after ε, ι or ρ stays through the singular (χώρας), others turn to η
+/// in the genitive and dative (θαλάσσης). An α written without its length keeps it unmarked,
+/// for the lemma's accent to tell.
+pub fn inflect(lemma: &str, gender: Gender) -> Result<Box<NounInflectionNumbers>, SafeError> {
+    let singular: Endings = match gender {
+        Gender::Feminine => match lemma.chars().last() {
+            Some('η') => ["η", "ης", "ῃ", "ην", "η"],
+            Some('ᾱ') => ["ᾱ", "ᾱς", "ᾳ", "ᾱν", "ᾱ"],
+            Some(x @ ('ᾰ' | 'α')) => {
+                let pure = lemma
+                    .chars()
+                    .rev()
+                    .nth(1)
+                    .is_some_and(|x| matches!(x, 'ε' | 'ι' | 'ρ'));
+                match (x, pure) {
+                    ('ᾰ', true) => ["ᾰ", "ᾱς", "ᾳ", "ᾰν", "ᾰ"],
+                    ('ᾰ', false) => ["ᾰ", "ης", "ῃ", "ᾰν", "ᾰ"],
+                    (_, true) => ["α", "ᾱς", "ᾳ", "αν", "α"],
+                    (_, false) => ["α", "ης", "ῃ", "αν", "α"],
+                }
+            }
+            _ => return Err(format!("could not match 1st declension lemma {lemma}").into()),
+        },
+        // -της nouns have a short α vocative: κριτά
+        Gender::Masculine if strip_ending(lemma, "της").is_some() => {
+            ["ης", "ου", "ῃ", "ην", "ᾰ"]
+        }
+        Gender::Masculine if strip_ending(lemma, "ης").is_some() => ["ης", "ου", "ῃ", "ην", "η"],
+        Gender::Masculine => ["ᾱς", "ου", "ᾳ", "ᾱν", "ᾱ"],
+        Gender::Neuter => {
+            return Err(format!("the 1st declension has no neuter, for {lemma}").into());
+        }
+    };
+
+    let stem = strip_ending(lemma, singular[0])
+        .ok_or_else(|| format!("could not match 1st declension lemma {lemma}"))?;
+    let stems = Stems {
+        short: stem,
+        long: stem,
+    };
+
+    Ok(decline(&stems, singular, DUAL, PLURAL))
+}
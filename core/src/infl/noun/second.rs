@@ -0,0 +1,42 @@
+use crate::{
+    api::lexicon::lexicon_model::NounInflectionNumbers, error::SafeError, grammar::Gender,
+};
+
+use super::{decline, strip_ending, Endings, Stems};
+
+const DUAL: Endings = ["ω", "οιν", "οιν", "ω", "ω"];
+
+/// -ος masculine and feminine nouns like λόγος and ὁδός, and -ον neuters like δῶρον.
+pub fn inflect(
+    lemma: &str,
+    genitive: Option<&str>,
+    gender: Gender,
+) -> Result<Box<NounInflectionNumbers>, SafeError> {
+    let nominative = match gender {
+        Gender::Neuter => "ον",
+        _ => "ος",
+    };
+    let short = strip_ending(lemma, nominative)
+        .ok_or_else(|| format!("could not match 2nd declension lemma {lemma}"))?;
+    let long = match genitive {
+        Some(genitive) => strip_ending(genitive, "ου")
+            .ok_or_else(|| format!("could not match 2nd declension genitive {genitive}"))?,
+        None => short,
+    };
+    let stems = Stems { short, long };
+
+    Ok(match gender {
+        Gender::Neuter => decline(
+            &stems,
+            ["ον", "ου", "ῳ", "ον", "ον"],
+            DUAL,
+            ["α", "ων", "οις", "α", "α"],
+        ),
+        _ => decline(
+            &stems,
+            ["ος", "ου", "ῳ", "ον", "ε"],
+            DUAL,
+            ["οι", "ων", "οις", "ους", "οι"],
+        ),
+    })
+}
@@ -0,0 +1,164 @@
+use crate::{
+    api::lexicon::lexicon_model::NounInflectionNumbers, error::SafeError, grammar::Gender,
+    utils::str::syllables::vowel_quantity,
+};
+
+use super::{decline, forms, lemma_form, strip_ending, Endings, Stems};
+
+const CONSONANT_DUAL: Endings = ["ε", "οιν", "οιν", "ε", "ε"];
+const EPSILON_DUAL: Endings = ["ει", "εοιν", "εοιν", "ει", "ει"];
+const SIGMA_DUAL: Endings = ["ει", "οιν", "οιν", "ει", "ει"];
+
+/// 3rd declension nouns, told apart by their genitive singular: consonant stems (φύλαξ,
+/// φύλακος), ι and υ stems (πόλις, πόλεως; ἰχθύς, ἰχθύος), σ stems (γένος, γένους) and ευ
+/// stems (βασιλεύς, βασιλέως).
+pub fn inflect(
+    lemma: &str,
+    genitive: &str,
+    gender: Gender,
+) -> Result<Box<NounInflectionNumbers>, SafeError> {
+    let matches = |nominative: &str, genitive_ending: &str| {
+        Some((
+            strip_ending(lemma, nominative)?,
+            strip_ending(genitive, genitive_ending)?,
+        ))
+    };
+
+    if let Some((short, long)) = matches("ευς", "εως") {
+        return Ok(decline(
+            &Stems { short, long },
+            ["ευς", "εως", "ει", "εα", "ευ"],
+            ["η", "εοιν", "εοιν", "η", "η"],
+            ["εις", "εων", "ευσι|ευσιν", "εας", "εις"],
+        ));
+    }
+    if let Some((short, long)) = matches("ις", "εως") {
+        return Ok(decline(
+            &Stems { short, long },
+            ["ις", "εως", "ει", "ιν", "ι"],
+            EPSILON_DUAL,
+            ["εις", "εων", "εσι|εσιν", "εις", "εις"],
+        ));
+    }
+    if let Some((short, long)) = matches("υς", "εως") {
+        return Ok(decline(
+            &Stems { short, long },
+            ["υς", "εως", "ει", "υν", "υ"],
+            EPSILON_DUAL,
+            ["εις", "εων", "εσι|εσιν", "εις", "εις"],
+        ));
+    }
+    if let Some((short, long)) = matches("υς", "υος") {
+        return Ok(decline(
+            &Stems { short, long },
+            ["υς", "υος", "υι", "υν", "υ"],
+            ["υε", "υοιν", "υοιν", "υε", "υε"],
+            ["υες", "υων", "υσι|υσιν", "υς", "υες"],
+        ));
+    }
+    if let (Gender::Neuter, Some((short, long))) = (gender, matches("ος", "ους")) {
+        return Ok(decline(
+            &Stems { short, long },
+            ["ος", "ους", "ει", "ος", "ος"],
+            SIGMA_DUAL,
+            ["η", "ων", "εσι|εσιν", "η", "η"],
+        ));
+    }
+    if let Some((short, long)) = matches("ης", "ους") {
+        return Ok(decline(
+            &Stems { short, long },
+            ["ης", "ους", "ει", "η", "ες"],
+            SIGMA_DUAL,
+            ["εις", "ων", "εσι|εσιν", "εις", "εις"],
+        ));
+    }
+
+    let stem = strip_ending(genitive, "ος")
+        .ok_or_else(|| format!("could not match 3rd declension genitive {genitive}"))?;
+    inflect_consonant(lemma, stem, gender)
+}
+
+/// Consonant stems take the nominative as it is, and their dative plural merges the stem's
+/// last consonant with the σ. Syncopated ρ stems (πατήρ, πατρός; ἀνήρ, ἀνδρός) lengthen their
+/// stem outside of a few cases, which isn't modeled.
+fn inflect_consonant(
+    lemma: &str,
+    stem: &str,
+    gender: Gender,
+) -> Result<Box<NounInflectionNumbers>, SafeError> {
+    let mut last = stem.chars().rev();
+    if last.next() == Some('ρ') && last.next().is_some_and(|x| vowel_quantity(x).is_none()) {
+        return Err(format!("cannot inflect syncopated 3rd declension stem {stem}").into());
+    }
+
+    let stems = Stems {
+        short: stem,
+        long: stem,
+    };
+    let mut numbers = match gender {
+        Gender::Neuter => decline(
+            &stems,
+            ["", "ος", "ι", "", ""],
+            CONSONANT_DUAL,
+            ["α", "ων", "", "α", "α"],
+        ),
+        _ => decline(
+            &stems,
+            ["", "ος", "ι", "α", ""],
+            CONSONANT_DUAL,
+            ["ες", "ων", "", "ας", "ες"],
+        ),
+    };
+
+    if let Some(singular) = numbers.singular.as_mut() {
+        singular.nominative = lemma_form(lemma);
+        singular.vocative = lemma_form(lemma);
+        if gender == Gender::Neuter {
+            singular.accusative = lemma_form(lemma);
+        }
+    }
+    if let Some(plural) = numbers.plural.as_mut() {
+        plural.dative = Some(forms(&dative_plural_stem(stem), "ι|ιν"));
+    }
+
+    Ok(numbers)
+}
+
+/// Stem and σ of the dative plural: φύλακ → φύλαξ, ἐλπίδ → ἐλπίσ, γέροντ → γέρουσ.
+fn dative_plural_stem(stem: &str) -> String {
+    let mut stem = stem.to_string();
+
+    if let Some(rest) = stem.strip_suffix("ντ") {
+        let mut rest = rest.to_string();
+        match rest.pop() {
+            Some('ο') => rest.push_str("ου"),
+            Some('ε') => rest.push_str("ει"),
+            Some(x) => rest.push(x),
+            None => {}
+        }
+        return rest + "σ";
+    }
+
+    // A dental drops, and so does a ν before it, leaving what's before to meet the σ: νυκτ-,
+    // νυξί.
+    if stem.ends_with(['τ', 'δ', 'θ']) {
+        stem.pop();
+        if stem.ends_with('ν') {
+            stem.pop();
+        }
+        if !stem.ends_with(['π', 'β', 'φ', 'κ', 'γ', 'χ']) {
+            return stem + "σ";
+        }
+    }
+
+    match stem.pop() {
+        Some('ν') => stem + "σ",
+        Some('π' | 'β' | 'φ') => stem + "ψ",
+        Some('κ' | 'γ' | 'χ') => stem + "ξ",
+        Some(x) => {
+            stem.push(x);
+            stem + "σ"
+        }
+        None => stem,
+    }
+}
@@ -4,83 +4,936 @@ use crate::{
         VerbInflectionPersons, VerbInflectionTenses, VerbInflectionThemes, VerbInflectionVoices,
     },
     error::SafeError,
-    utils::str::remove_diacritics::remove_diacritics_char,
+    grammar::Voice,
+    infl::accent::{self, Accentuation},
+    utils::str::remove_diacritics::{remove_diacritics_with, Diacritic},
 };
 
-#[allow(dead_code)]
-pub fn inflect(lemma: &str) -> Result<VerbInflectionTenses, SafeError> {
-    if lemma.ends_with('ω') {
-        return Ok(inflect_w(lemma));
+mod athematic;
+mod augment;
+mod contract;
+mod endings;
+mod participle;
+
+use augment::{augment, augment_pluperfect, unaugment};
+use endings::*;
+use participle::decline_participle;
+
+/// Endings by number (singular, dual, plural) and person. Alternatives are separated by `|`,
+/// and `-` marks a form that doesn't exist, like the 1st person dual active.
+type Endings<S = &'static str> = [[S; 3]; 3];
+
+/// Endings of one tense and voice, by mood.
+#[derive(Default)]
+struct Conjugation<S = &'static str> {
+    indicative: Option<Endings<S>>,
+    subjunctive: Option<Endings<S>>,
+    optative: Option<Endings<S>>,
+    imperative: Option<Endings<S>>,
+    infinitive: Option<(S, Accentuation)>,
+    /// Masculine, feminine and neuter nominatives, and the genitive.
+    participle: Option<([S; 4], Accentuation)>,
+    /// Whether the subjunctive and optative are accented on their ending, contracted with the
+    /// stem's vowel, instead of recessively: λυθῶ, τιθείην.
+    ending_accent: bool,
+}
+
+/// Stem of the indicative, with the augment of past tenses, and of the other moods.
+struct Stems<'a> {
+    augmented: &'a str,
+    plain: &'a str,
+    /// Whether the stem's last vowel contracts with the endings, as in -άω, -έω and -όω verbs.
+    contract: bool,
+}
+
+/// The principal parts a verb is conjugated from, all 1st person singular indicatives: λύω,
+/// λύσω, ἔλυσα, λέλυκα, λέλυμαι, ἐλύθην.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PrincipalParts {
+    pub present: String,
+    pub future: Option<String>,
+    pub aorist: Option<String>,
+    pub perfect: Option<String>,
+    pub perfect_middle: Option<String>,
+    pub aorist_passive: Option<String>,
+}
+
+/// Conjugates a verb from its principal parts, each filling the tenses built on it.
+pub fn inflect(parts: &PrincipalParts) -> Result<VerbInflectionTenses, SafeError> {
+    let mut tenses = VerbInflectionTenses::default();
+    let present = unaccented(&parts.present);
+
+    inflect_present(&mut tenses, &present)?;
+    if let Some(future) = &parts.future {
+        inflect_future(&mut tenses, future, &present)?;
+    }
+    if let Some(aorist) = &parts.aorist {
+        inflect_aorist(&mut tenses, &unaccented(aorist), &present)?;
+    }
+    if let Some(perfect) = &parts.perfect {
+        inflect_perfect(&mut tenses, &unaccented(perfect))?;
+    }
+    if let Some(perfect_middle) = &parts.perfect_middle {
+        inflect_perfect_middle(&mut tenses, &unaccented(perfect_middle))?;
     }
-    Err("cannot inflect verb".into())
+    if let Some(aorist_passive) = &parts.aorist_passive {
+        inflect_aorist_passive(&mut tenses, &unaccented(aorist_passive), &present)?;
+    }
+
+    Ok(tenses)
 }
 
-fn inflect_w(lemma: &str) -> VerbInflectionTenses {
-    VerbInflectionTenses {
-        present: Some(Box::from(inflect_w_pres(lemma))),
-        ..Default::default()
+/// Present and imperfect: thematic -ω verbs, contracted when their stem ends in α, ε or ο,
+/// athematic -μι ones, and their deponents in -ομαι and -μαι.
+fn inflect_present(tenses: &mut VerbInflectionTenses, present: &str) -> Result<(), SafeError> {
+    if let Some(stem) = present.strip_suffix("μι") {
+        return athematic::inflect(tenses, stem, true);
+    }
+
+    let (stem, active) = if let Some(stem) = present.strip_suffix("ομαι") {
+        (stem, false)
+    } else if let Some(stem) = present.strip_suffix("μαι") {
+        return athematic::inflect(tenses, stem, false);
+    } else if let Some(stem) = present.strip_suffix('ω') {
+        (stem, true)
+    } else {
+        return Err(format!("cannot inflect verb {present}").into());
+    };
+
+    let augmented = augment(stem);
+    let contract = stem.ends_with(['α', 'ε', 'ο']);
+    let stems = Stems {
+        augmented: stem,
+        plain: stem,
+        contract,
+    };
+    let imperfect = Stems {
+        augmented: &augmented,
+        ..stems
+    };
+
+    if active {
+        let conjugation = match contract {
+            true => &PRESENT_ACTIVE_CONTRACT,
+            false => &PRESENT_ACTIVE,
+        };
+        conjugate(
+            &mut tenses.present,
+            false,
+            &[Voice::Active],
+            &stems,
+            conjugation,
+        )?;
+        conjugate(
+            &mut tenses.imperfect,
+            false,
+            &[Voice::Active],
+            &imperfect,
+            &IMPERFECT_ACTIVE,
+        )?;
     }
+    let mediopassive = [Voice::Middle, Voice::Passive];
+    conjugate(
+        &mut tenses.present,
+        false,
+        &mediopassive,
+        &stems,
+        &PRESENT_MIDDLE,
+    )?;
+    conjugate(
+        &mut tenses.imperfect,
+        false,
+        &mediopassive,
+        &imperfect,
+        &IMPERFECT_MIDDLE,
+    )
 }
 
-fn inflect_w_pres(lemma: &str) -> VerbInflectionThemes {
-    VerbInflectionThemes {
-        thematic: Some(VerbInflectionContractions {
-            contracted: Some(VerbInflectionMoods {
-                indicative: Some(inflect_w_pre_ind(lemma)),
-                ..Default::default()
-            }),
-            ..Default::default()
-        }),
-        ..Default::default()
+/// The future, sigmatic (λύσω) or contracted like an -έω present (μενῶ, from μενέω).
+fn inflect_future(
+    tenses: &mut VerbInflectionTenses,
+    future: &str,
+    present: &str,
+) -> Result<(), SafeError> {
+    let contract = future.ends_with('ῶ') || future.ends_with("οῦμαι");
+    let future = unaccented(future);
+
+    let (stem, active) = if let Some(stem) = future.strip_suffix("ουμαι") {
+        (format!("{stem}ε"), false)
+    } else if let Some(stem) = future.strip_suffix("ομαι") {
+        (stem.to_string(), false)
+    } else if let Some(stem) = future.strip_suffix('ω') {
+        match contract {
+            true => (format!("{stem}ε"), true),
+            false => (stem.to_string(), true),
+        }
+    } else {
+        return Err(format!("cannot inflect future {future}").into());
+    };
+    let stem = lengthened(&stem, present);
+
+    let stems = Stems {
+        augmented: &stem,
+        plain: &stem,
+        contract,
+    };
+    if active {
+        conjugate(
+            &mut tenses.future,
+            false,
+            &[Voice::Active],
+            &stems,
+            &FUTURE_ACTIVE,
+        )?;
     }
+    conjugate(
+        &mut tenses.future,
+        false,
+        &[Voice::Middle],
+        &stems,
+        &FUTURE_MIDDLE,
+    )
+}
+
+/// The aorist, 1st (ἔλυσα) or 2nd (ἔλαβον) in both voices, 1st or 2nd in the middle only for
+/// deponents (ἐλυσάμην, ἐγενόμην), or a root aorist (ἔβην).
+fn inflect_aorist(
+    tenses: &mut VerbInflectionTenses,
+    aorist: &str,
+    present: &str,
+) -> Result<(), SafeError> {
+    let aorist_stem = |ending: &str| {
+        aorist.strip_suffix(ending).map(|x| {
+            let plain = unaugment(x, present);
+            (lengthened(x, present), lengthened(&plain, present))
+        })
+    };
+
+    if let Some((augmented, plain)) = aorist_stem("αμην") {
+        let stems = stems(&augmented, &plain);
+        return conjugate(
+            &mut tenses.aorist,
+            false,
+            &[Voice::Middle],
+            &stems,
+            &FIRST_AORIST_MIDDLE,
+        );
+    }
+    if let Some((augmented, plain)) = aorist_stem("ομην") {
+        let stems = stems(&augmented, &plain);
+        return conjugate(
+            &mut tenses.aorist_2nd,
+            false,
+            &[Voice::Middle],
+            &stems,
+            &SECOND_AORIST_MIDDLE,
+        );
+    }
+    if let Some((augmented, plain)) = aorist_stem("α") {
+        let stems = stems(&augmented, &plain);
+        conjugate(
+            &mut tenses.aorist,
+            false,
+            &[Voice::Active],
+            &stems,
+            &FIRST_AORIST_ACTIVE,
+        )?;
+        return conjugate(
+            &mut tenses.aorist,
+            false,
+            &[Voice::Middle],
+            &stems,
+            &FIRST_AORIST_MIDDLE,
+        );
+    }
+    if let Some((augmented, plain)) = aorist_stem("ον") {
+        let stems = stems(&augmented, &plain);
+        conjugate(
+            &mut tenses.aorist_2nd,
+            false,
+            &[Voice::Active],
+            &stems,
+            &SECOND_AORIST_ACTIVE,
+        )?;
+        return conjugate(
+            &mut tenses.aorist_2nd,
+            false,
+            &[Voice::Middle],
+            &stems,
+            &SECOND_AORIST_MIDDLE,
+        );
+    }
+    if let Some((augmented, plain)) = aorist_stem("ν") {
+        let stems = stems(&augmented, &plain);
+        return conjugate(
+            &mut tenses.aorist_2nd,
+            true,
+            &[Voice::Active],
+            &stems,
+            &ROOT_AORIST,
+        );
+    }
+
+    Err(format!("cannot inflect aorist {aorist}").into())
+}
+
+/// The perfect active and pluperfect, in the 2nd perfect when the stem has no κ (γέγραφα).
+fn inflect_perfect(tenses: &mut VerbInflectionTenses, perfect: &str) -> Result<(), SafeError> {
+    let stem = perfect
+        .strip_suffix('α')
+        .ok_or_else(|| format!("cannot inflect perfect {perfect}"))?;
+    let augmented = augment_pluperfect(stem);
+
+    let tense = match stem.ends_with('κ') {
+        true => &mut tenses.perfect,
+        false => &mut tenses.perfect_2nd,
+    };
+    conjugate(
+        tense,
+        false,
+        &[Voice::Active],
+        &stems(stem, stem),
+        &PERFECT_ACTIVE,
+    )?;
+    conjugate(
+        &mut tenses.pluperfect,
+        false,
+        &[Voice::Active],
+        &stems(&augmented, stem),
+        &PLUPERFECT_ACTIVE,
+    )
+}
+
+/// The perfect middle, pluperfect middle and future perfect of a vowel stem (λέλυμαι). Those of
+/// consonant stems assimilate with each ending (γέγραμμαι, γέγραψαι) and are not generated.
+fn inflect_perfect_middle(
+    tenses: &mut VerbInflectionTenses,
+    perfect: &str,
+) -> Result<(), SafeError> {
+    let stem = perfect
+        .strip_suffix("μαι")
+        .ok_or_else(|| format!("cannot inflect perfect middle {perfect}"))?;
+    if !unaccented(stem).ends_with(['α', 'ε', 'η', 'ι', 'ο', 'υ', 'ω']) {
+        return Ok(());
+    }
+
+    let augmented = augment_pluperfect(stem);
+    let mediopassive = [Voice::Middle, Voice::Passive];
+    conjugate(
+        &mut tenses.perfect,
+        false,
+        &mediopassive,
+        &stems(stem, stem),
+        &PERFECT_MIDDLE,
+    )?;
+    conjugate(
+        &mut tenses.pluperfect,
+        false,
+        &mediopassive,
+        &stems(&augmented, stem),
+        &PLUPERFECT_MIDDLE,
+    )?;
+
+    let future = format!("{stem}σ");
+    conjugate(
+        &mut tenses.future_perfect,
+        false,
+        &mediopassive,
+        &stems(&future, &future),
+        &FUTURE_MIDDLE,
+    )
+}
+
+/// The aorist passive, 1st (ἐλύθην) or 2nd (ἐγράφην), and the future passive built on it
+/// (λυθήσομαι).
+fn inflect_aorist_passive(
+    tenses: &mut VerbInflectionTenses,
+    aorist: &str,
+    present: &str,
+) -> Result<(), SafeError> {
+    let augmented = aorist
+        .strip_suffix("ην")
+        .ok_or_else(|| format!("cannot inflect aorist passive {aorist}"))?;
+    let plain = unaugment(augmented, present);
+
+    let (tense, conjugation) = match plain.ends_with('θ') {
+        true => (&mut tenses.aorist, &AORIST_PASSIVE),
+        false => (&mut tenses.aorist_2nd, &SECOND_AORIST_PASSIVE),
+    };
+    conjugate(
+        tense,
+        false,
+        &[Voice::Passive],
+        &stems(augmented, &plain),
+        conjugation,
+    )?;
+
+    let future = format!("{plain}ησ");
+    conjugate(
+        &mut tenses.future,
+        false,
+        &[Voice::Passive],
+        &stems(&future, &future),
+        &FUTURE_MIDDLE,
+    )
 }
 
-fn uncontracted(stem: &str, ending: &str) -> Option<Vec<InflectionForm>> {
-    Some(vec![InflectionForm {
-        uncontracted: Some(vec![stem.to_string(), ending.to_string()]),
-        ..Default::default()
-    }])
+/// `stem` with the vowel before its σ marked long where the present ends in it, as pure ι, υ
+/// and α stems keep it long in the future and aorist (λύω, λῡσ- → λῦσαι; δράω, δρᾱσ- → δρᾶσαι),
+/// for the accent to tell. The α of γελάω is short, as the one of σχίζω, σχισ- is.
+fn lengthened(stem: &str, present: &str) -> String {
+    let Some(rest) = stem.strip_suffix('σ') else {
+        return stem.to_string();
+    };
+    let mut chars = rest.chars().rev();
+    let (Some(last), before) = (chars.next(), chars.next()) else {
+        return stem.to_string();
+    };
+    let long = match (last, before) {
+        ('ι', _) => 'ῑ',
+        ('υ', _) => 'ῡ',
+        ('α', Some('ε' | 'ι' | 'ρ')) => 'ᾱ',
+        _ => return stem.to_string(),
+    };
+
+    let pure = present
+        .strip_suffix('ω')
+        .or(present.strip_suffix("ομαι"))
+        .is_some_and(|x| x.ends_with(last));
+    match pure {
+        true => format!("{}{long}σ", skip_last(rest)),
+        false => stem.to_string(),
+    }
 }
 
-fn is_vowel(c: char) -> bool {
-    matches!(
-        remove_diacritics_char(c),
-        'α' | 'ε' | 'η' | 'ι' | 'ο' | 'υ' | 'ω'
+/// `s` without its accents, which depend on the ending and are put back by the accentuation.
+fn unaccented(s: &str) -> String {
+    remove_diacritics_with(
+        s,
+        &[
+            Diacritic::SmoothBreathing,
+            Diacritic::RoughBreathing,
+            Diacritic::IotaSubscript,
+            Diacritic::Diaeresis,
+        ],
     )
 }
 
-fn split_syllables(word: &str) {
-    let mut syllables = Vec::new();
-    let mut syllable = String::new();
-    let mut last_vowel = false;
+fn skip_last(s: &str) -> &str {
+    s.char_indices().last().map_or(s, |(i, _)| &s[..i])
+}
 
-    for c in word.chars() {
-        if is_vowel(c) {
-            if last_vowel {
-                syllables.push(syllable);
-                syllable = String::new();
+fn stems<'a>(augmented: &'a str, plain: &'a str) -> Stems<'a> {
+    Stems {
+        augmented,
+        plain,
+        contract: false,
+    }
+}
+
+/// `stem` and `ending` joined, contracting the stem's last vowel if asked.
+fn join(stem: &str, ending: &str, contract: bool) -> String {
+    match contract {
+        true => contract::contract(stem, ending),
+        false => format!("{stem}{ending}"),
+    }
+}
+
+/// `stem` and `ending` joined and accented. Contracted forms take the accent of the
+/// uncontracted ones, and final -αι and -οι count as long in the `optative`.
+fn accented(
+    stem: &str,
+    ending: &str,
+    contract: bool,
+    accentuation: Accentuation,
+    optative: bool,
+) -> String {
+    let form = join(stem, ending, contract);
+    match contract {
+        true => accent::contracted(stem, ending, &form, optative),
+        false => accent::accentuate(&form, ending, accentuation, optative),
+    }
+}
+
+fn forms(
+    stem: &str,
+    endings: &str,
+    contract: bool,
+    accentuation: Accentuation,
+    optative: bool,
+) -> Vec<InflectionForm> {
+    endings
+        .split('|')
+        // The movable ν of -ε doesn't survive contraction: ἐτίμα, ἐποίει.
+        .filter(|x| !(contract && *x == "εν"))
+        .map(|ending| InflectionForm {
+            contracted: Some(accented(stem, ending, contract, accentuation, optative)),
+            uncontracted: Some(vec![stem.to_string(), ending.to_string()]),
+        })
+        .collect()
+}
+
+fn persons<S: AsRef<str>>(
+    stem: &str,
+    endings: &[S; 3],
+    contract: bool,
+    accentuation: Accentuation,
+    optative: bool,
+) -> Option<VerbInflectionPersons> {
+    let [first, second, third] = endings.each_ref().map(|x| match x.as_ref() {
+        "-" => None,
+        x => Some(forms(stem, x, contract, accentuation, optative)),
+    });
+
+    (first.is_some() || second.is_some() || third.is_some()).then_some(VerbInflectionPersons {
+        first,
+        second,
+        third,
+    })
+}
+
+fn numbers<S: AsRef<str>>(
+    stem: &str,
+    endings: &Endings<S>,
+    contract: bool,
+    accentuation: Accentuation,
+    optative: bool,
+) -> VerbInflectionNumbers {
+    let [singular, dual, plural] = endings
+        .each_ref()
+        .map(|x| persons(stem, x, contract, accentuation, optative));
+
+    VerbInflectionNumbers {
+        singular,
+        plural,
+        dual,
+    }
+}
+
+/// Puts `value` in the slot of `voice`.
+fn set_voice<T>(
+    voice: Voice,
+    value: T,
+    active: &mut Option<T>,
+    middle: &mut Option<T>,
+    passive: &mut Option<T>,
+) {
+    let slot = match voice {
+        Voice::Active => active,
+        Voice::Middle => middle,
+        Voice::Passive => passive,
+    };
+    *slot = Some(value);
+}
+
+fn moods_mut(
+    tense: &mut Option<Box<VerbInflectionThemes>>,
+    athematic: bool,
+    uncontracted: bool,
+) -> &mut VerbInflectionMoods {
+    let themes = tense.get_or_insert_with(Default::default);
+    let contractions: &mut VerbInflectionContractions = match athematic {
+        true => themes.athematic.get_or_insert_with(Default::default),
+        false => themes.thematic.get_or_insert_with(Default::default),
+    };
+
+    match uncontracted {
+        true => contractions
+            .uncontracted
+            .get_or_insert_with(Default::default),
+        false => contractions.contracted.get_or_insert_with(Default::default),
+    }
+}
+
+/// Fills `tense` with the forms of `conjugation` in each of `voices`. Contract verbs get both
+/// their contracted forms and the uncontracted ones they come from.
+fn conjugate<S: AsRef<str>>(
+    tense: &mut Option<Box<VerbInflectionThemes>>,
+    athematic: bool,
+    voices: &[Voice],
+    stems: &Stems,
+    conjugation: &Conjugation<S>,
+) -> Result<(), SafeError> {
+    let contractions: &[bool] = match stems.contract {
+        true => &[true, false],
+        false => &[false],
+    };
+
+    for &contract in contractions {
+        let moods = moods_mut(tense, athematic, stems.contract && !contract);
+        let participle = conjugation
+            .participle
+            .as_ref()
+            .map(|(endings, accentuation)| {
+                let [_, feminine, neuter, genitive] = endings
+                    .each_ref()
+                    .map(|x| join(stems.plain, x.as_ref(), contract));
+                let masculine = accented(
+                    stems.plain,
+                    endings[0].as_ref(),
+                    contract,
+                    *accentuation,
+                    false,
+                );
+                decline_participle([masculine, feminine, neuter, genitive])
+            })
+            .transpose()?;
+        let ending_accent = match conjugation.ending_accent {
+            true => Accentuation::Ending,
+            false => Accentuation::Recessive,
+        };
+
+        for &voice in voices {
+            let finite = [
+                (
+                    &mut moods.indicative,
+                    &conjugation.indicative,
+                    stems.augmented,
+                    Accentuation::Recessive,
+                    false,
+                ),
+                (
+                    &mut moods.subjunctive,
+                    &conjugation.subjunctive,
+                    stems.plain,
+                    ending_accent,
+                    false,
+                ),
+                (
+                    &mut moods.optative,
+                    &conjugation.optative,
+                    stems.plain,
+                    ending_accent,
+                    true,
+                ),
+                (
+                    &mut moods.imperative,
+                    &conjugation.imperative,
+                    stems.plain,
+                    Accentuation::Recessive,
+                    false,
+                ),
+            ];
+            for (mood, endings, stem, accentuation, optative) in finite {
+                if let Some(endings) = endings {
+                    let voices: &mut VerbInflectionVoices =
+                        mood.get_or_insert_with(Default::default);
+                    set_voice(
+                        voice,
+                        numbers(stem, endings, contract, accentuation, optative),
+                        &mut voices.active,
+                        &mut voices.middle,
+                        &mut voices.passive,
+                    );
+                }
+            }
+
+            if let Some((ending, accentuation)) = &conjugation.infinitive {
+                let infinitive = moods.infinitive.get_or_insert_with(Default::default);
+                set_voice(
+                    voice,
+                    forms(stems.plain, ending.as_ref(), contract, *accentuation, false),
+                    &mut infinitive.active,
+                    &mut infinitive.middle,
+                    &mut infinitive.passive,
+                );
+            }
+            if let Some(genders) = &participle {
+                let participle = moods.participle.get_or_insert_with(Default::default);
+                set_voice(
+                    voice,
+                    genders.clone(),
+                    &mut participle.active,
+                    &mut participle.middle,
+                    &mut participle.passive,
+                );
             }
-            last_vowel = true;
-        } else {
-            last_vowel = false;
         }
-        syllable.push(c);
     }
-    syllables.push(syllable);
+
+    Ok(())
 }
 
-fn inflect_w_pre_ind(lemma: &str) -> VerbInflectionVoices {
-    let stem = &lemma[..lemma.len() - 1];
-    dbg!(split_syllables(stem));
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Forms of the cell at `path` of `tenses`, a JSON pointer, alternatives joined by `|`.
+    fn cell(tenses: &VerbInflectionTenses, path: &str) -> String {
+        let value = serde_json::to_value(tenses).unwrap();
+        let forms = value.pointer(path).and_then(|x| x.as_array());
+        forms
+            .unwrap_or_else(|| panic!("no cell at {path}"))
+            .iter()
+            .filter_map(|x| x["contracted"].as_str())
+            .collect::<Vec<_>>()
+            .join("|")
+    }
+
+    fn assert_cells(tenses: &VerbInflectionTenses, cells: &[(&str, &str)]) {
+        for (path, expected) in cells {
+            assert_eq!(cell(tenses, path), *expected, "at {path}");
+        }
+    }
 
-    VerbInflectionVoices {
-        active: Some(VerbInflectionNumbers {
-            singular: Some(VerbInflectionPersons {
-                first: uncontracted(stem, "ω"),
-                ..Default::default()
-            }),
+    fn parts(
+        present: &str,
+        future: Option<&str>,
+        aorist: Option<&str>,
+        perfect: Option<&str>,
+    ) -> PrincipalParts {
+        PrincipalParts {
+            present: present.to_string(),
+            future: future.map(str::to_string),
+            aorist: aorist.map(str::to_string),
+            perfect: perfect.map(str::to_string),
             ..Default::default()
-        }),
-        ..Default::default()
+        }
+    }
+
+    #[test]
+    fn conjugates_from_all_principal_parts() {
+        let tenses = inflect(&PrincipalParts {
+            present: "λύω".to_string(),
+            future: Some("λύσω".to_string()),
+            aorist: Some("ἔλυσα".to_string()),
+            perfect: Some("λέλυκα".to_string()),
+            perfect_middle: Some("λέλυμαι".to_string()),
+            aorist_passive: Some("ἐλύθην".to_string()),
+        })
+        .unwrap();
+
+        assert_cells(
+            &tenses,
+            &[
+                (
+                    "/present/thematic/contracted/indicative/active/plural/third",
+                    "λύουσι|λύουσιν",
+                ),
+                (
+                    "/present/thematic/contracted/optative/middle/singular/first",
+                    "λυοίμην",
+                ),
+                (
+                    "/imperfect/thematic/contracted/indicative/active/singular/first",
+                    "ἔλυον",
+                ),
+                (
+                    "/future/thematic/contracted/indicative/middle/singular/second",
+                    "λύσῃ|λύσει",
+                ),
+                (
+                    "/future/thematic/contracted/indicative/passive/singular/first",
+                    "λυθήσομαι",
+                ),
+                (
+                    "/aorist/thematic/contracted/indicative/active/plural/first",
+                    "ἐλύσαμεν",
+                ),
+                (
+                    "/aorist/thematic/contracted/indicative/middle/singular/second",
+                    "ἐλύσω",
+                ),
+                (
+                    "/aorist/thematic/contracted/optative/active/singular/third",
+                    "λύσαι|λύσειε|λύσειεν",
+                ),
+                (
+                    "/perfect/thematic/contracted/indicative/active/plural/third",
+                    "λελύκασι|λελύκασιν",
+                ),
+                (
+                    "/perfect/thematic/contracted/indicative/middle/singular/second",
+                    "λέλυσαι",
+                ),
+                (
+                    "/perfect/thematic/contracted/infinitive/active",
+                    "λελυκέναι",
+                ),
+                (
+                    "/pluperfect/thematic/contracted/indicative/active/singular/first",
+                    "ἐλελύκη|ἐλελύκειν",
+                ),
+                (
+                    "/future_perfect/thematic/contracted/indicative/middle/singular/first",
+                    "λελύσομαι",
+                ),
+                (
+                    "/aorist/thematic/contracted/indicative/passive/singular/first",
+                    "ἐλύθην",
+                ),
+                (
+                    "/aorist/thematic/contracted/subjunctive/passive/singular/first",
+                    "λυθῶ",
+                ),
+                ("/aorist/thematic/contracted/infinitive/active", "λῦσαι"),
+                (
+                    "/aorist/thematic/contracted/imperative/active/singular/second",
+                    "λῦσον",
+                ),
+                (
+                    "/aorist/thematic/contracted/imperative/middle/singular/second",
+                    "λῦσαι",
+                ),
+                ("/aorist/thematic/contracted/infinitive/passive", "λυθῆναι"),
+                (
+                    "/aorist/thematic/contracted/participle/passive/masculine/singular/genitive",
+                    "λυθέντος",
+                ),
+                (
+                    "/aorist/thematic/contracted/participle/active/neuter/singular/nominative",
+                    "λῦσαν",
+                ),
+                (
+                    "/present/thematic/contracted/participle/active/feminine/plural/accusative",
+                    "λυούσας",
+                ),
+                (
+                    "/perfect/thematic/contracted/participle/active/feminine/singular/nominative",
+                    "λελυκυῖα",
+                ),
+                (
+                    "/perfect/thematic/contracted/participle/active/feminine/singular/genitive",
+                    "λελυκυίας",
+                ),
+            ],
+        );
+    }
+
+    #[test]
+    fn conjugates_contract_verbs() {
+        let tenses = inflect(&parts(
+            "τιμάω",
+            Some("τιμήσω"),
+            Some("ἐτίμησα"),
+            Some("τετίμηκα"),
+        ))
+        .unwrap();
+        assert_cells(
+            &tenses,
+            &[
+                (
+                    "/present/thematic/contracted/indicative/active/singular/second",
+                    "τιμᾷς",
+                ),
+                ("/present/thematic/contracted/infinitive/active", "τιμᾶν"),
+                (
+                    "/present/thematic/uncontracted/indicative/active/singular/first",
+                    "τιμάω",
+                ),
+                (
+                    "/imperfect/thematic/contracted/indicative/active/singular/first",
+                    "ἐτίμων",
+                ),
+                ("/aorist/thematic/contracted/infinitive/active", "τιμῆσαι"),
+                (
+                    "/perfect/thematic/contracted/indicative/active/singular/first",
+                    "τετίμηκα",
+                ),
+            ],
+        );
+
+        let tenses = inflect(&parts("ποιέω", Some("ποιήσω"), None, None)).unwrap();
+        assert_cells(
+            &tenses,
+            &[
+                (
+                    "/present/thematic/contracted/indicative/active/singular/second",
+                    "ποιεῖς",
+                ),
+                (
+                    "/present/thematic/contracted/indicative/active/plural/first",
+                    "ποιοῦμεν",
+                ),
+                ("/present/thematic/contracted/infinitive/active", "ποιεῖν"),
+                (
+                    "/imperfect/thematic/contracted/indicative/active/singular/first",
+                    "ἐποίουν",
+                ),
+                (
+                    "/future/thematic/contracted/indicative/active/singular/first",
+                    "ποιήσω",
+                ),
+            ],
+        );
+
+        let tenses = inflect(&parts("δηλόω", None, Some("ἐδήλωσα"), None)).unwrap();
+        assert_cells(
+            &tenses,
+            &[
+                (
+                    "/present/thematic/contracted/indicative/active/singular/second",
+                    "δηλοῖς",
+                ),
+                (
+                    "/present/thematic/contracted/indicative/active/plural/first",
+                    "δηλοῦμεν",
+                ),
+                ("/present/thematic/contracted/infinitive/active", "δηλοῦν"),
+                (
+                    "/aorist/thematic/contracted/indicative/active/singular/first",
+                    "ἐδήλωσα",
+                ),
+            ],
+        );
+    }
+
+    #[test]
+    fn conjugates_athematic_verbs() {
+        let tenses = inflect(&parts("τίθημι", None, None, None)).unwrap();
+        assert_cells(
+            &tenses,
+            &[
+                (
+                    "/present/athematic/contracted/indicative/active/singular/third",
+                    "τίθησι|τίθησιν",
+                ),
+                (
+                    "/present/athematic/contracted/indicative/active/plural/third",
+                    "τιθέασι|τιθέασιν",
+                ),
+                (
+                    "/present/athematic/contracted/subjunctive/active/singular/first",
+                    "τιθῶ",
+                ),
+                ("/present/athematic/contracted/infinitive/active", "τιθέναι"),
+                (
+                    "/imperfect/athematic/contracted/indicative/active/singular/first",
+                    "ἐτίθην",
+                ),
+                (
+                    "/present/athematic/contracted/participle/active/masculine/plural/dative",
+                    "τιθεῖσι|τιθεῖσιν",
+                ),
+            ],
+        );
+
+        let tenses = inflect(&parts("δίδωμι", None, None, None)).unwrap();
+        assert_cells(
+            &tenses,
+            &[
+                (
+                    "/present/athematic/contracted/indicative/active/singular/third",
+                    "δίδωσι|δίδωσιν",
+                ),
+                (
+                    "/present/athematic/contracted/indicative/active/plural/third",
+                    "διδόασι|διδόασιν",
+                ),
+                (
+                    "/present/athematic/contracted/subjunctive/active/singular/second",
+                    "διδῷς",
+                ),
+                ("/present/athematic/contracted/infinitive/active", "διδόναι"),
+                (
+                    "/imperfect/athematic/contracted/indicative/active/singular/first",
+                    "ἐδίδουν",
+                ),
+                (
+                    "/present/athematic/contracted/participle/active/masculine/singular/genitive",
+                    "διδόντος",
+                ),
+            ],
+        );
     }
 }
@@ -0,0 +1,213 @@
+use crate::{
+    api::lexicon::lexicon_model::VerbInflectionTenses, error::SafeError, grammar::Voice,
+    infl::accent::Accentuation,
+};
+
+use super::{
+    augment::augment,
+    conjugate,
+    endings::{
+        ATHEMATIC_IMPERATIVE_MIDDLE, ATHEMATIC_MIDDLE, ATHEMATIC_SECONDARY_MIDDLE, OPTATIVE_ACTIVE,
+        OPTATIVE_MIDDLE, SUBJUNCTIVE_ACTIVE, SUBJUNCTIVE_MIDDLE,
+    },
+    Conjugation, Endings, Stems,
+};
+
+const OPTATIVE: Endings = [
+    ["ιην", "ιης", "ιη"],
+    ["-", "ιητον|ιτον", "ιητην|ιτην"],
+    ["ιμεν|ιημεν", "ιτε|ιητε", "ιεν|ιησαν"],
+];
+const OPTATIVE_MIDDLE_ATHEMATIC: Endings = [
+    ["ιμην", "ιο", "ιτο"],
+    ["ιμεθον", "ισθον", "ισθην"],
+    ["ιμεθα", "ισθε", "ιντο"],
+];
+
+/// Conjugates the present and imperfect of -μι verbs, from the stem of their lemma: the long
+/// one of an active (τιθη-, διδω-, ἱστη-, δεικνυ-) or the short one of a deponent (δυνα-).
+/// Their endings are all written from the stem without its vowel, which shortens outside of
+/// the active singular. Actives other than δείκνυμι accent their subjunctive and optative on
+/// the ending, where the stem's vowel contracts: τιθῶ, τιθείμην, but δύνωμαι.
+pub fn inflect(
+    tenses: &mut VerbInflectionTenses,
+    stem: &str,
+    active: bool,
+) -> Result<(), SafeError> {
+    let Some((split, last)) = stem.char_indices().last() else {
+        return Err("cannot inflect an empty athematic stem".into());
+    };
+    let base = &stem[..split];
+
+    let vowel = match (active, last) {
+        (true, 'η') if stem.ends_with("στη") || stem.ends_with("φη") => 'α',
+        (true, 'η') => 'ε',
+        (true, 'ω') => 'ο',
+        (true, 'υ') | (false, 'υ') => 'υ',
+        (false, x @ ('α' | 'ε' | 'ο')) => x,
+        _ => return Err(format!("cannot inflect athematic stem {stem}").into()),
+    };
+
+    let present = Stems {
+        augmented: base,
+        plain: base,
+        contract: false,
+    };
+    let augmented = augment(base);
+    let imperfect = Stems {
+        augmented: &augmented,
+        plain: base,
+        contract: false,
+    };
+
+    if active {
+        conjugate(
+            &mut tenses.present,
+            true,
+            &[Voice::Active],
+            &present,
+            &present_active(vowel),
+        )?;
+        conjugate(
+            &mut tenses.imperfect,
+            true,
+            &[Voice::Active],
+            &imperfect,
+            &imperfect_active(vowel),
+        )?;
+    }
+    conjugate(
+        &mut tenses.present,
+        true,
+        &[Voice::Middle, Voice::Passive],
+        &present,
+        &present_middle(vowel, active && vowel != 'υ'),
+    )?;
+    conjugate(
+        &mut tenses.imperfect,
+        true,
+        &[Voice::Middle, Voice::Passive],
+        &imperfect,
+        &Conjugation {
+            indicative: Some(prefixed(vowel, &ATHEMATIC_SECONDARY_MIDDLE)),
+            ..Default::default()
+        },
+    )
+}
+
+/// Each of `endings` with `f` applied to its alternatives.
+fn map_endings(endings: &Endings, f: impl Fn(&str) -> String) -> Endings<String> {
+    endings.map(|persons| {
+        persons.map(|x| match x {
+            "-" => x.to_string(),
+            _ => x.split('|').map(&f).collect::<Vec<_>>().join("|"),
+        })
+    })
+}
+
+fn prefixed(vowel: char, endings: &Endings) -> Endings<String> {
+    map_endings(endings, |x| format!("{vowel}{x}"))
+}
+
+/// The stem vowel merges into the subjunctive's: τιθῶ, ἱστῇς, and ο makes its η an ω: διδῷς.
+/// δεικνύω simply adds it.
+fn subjunctive(vowel: char, endings: &Endings) -> Endings<String> {
+    match vowel {
+        'ο' => map_endings(endings, |x| x.replace('η', "ω").replace('ῃ', "ῳ")),
+        'υ' => prefixed(vowel, endings),
+        _ => map_endings(endings, str::to_string),
+    }
+}
+
+fn long(vowel: char) -> char {
+    match vowel {
+        'α' | 'ε' => 'η',
+        'ο' => 'ω',
+        x => x,
+    }
+}
+
+fn present_active(v: char) -> Conjugation<String> {
+    let l = long(v);
+    let third_plural = match v {
+        'α' => "ασι|ασιν".to_string(),
+        _ => format!("{v}ασι|{v}ασιν"),
+    };
+    let imperative = match v {
+        'α' => "η",
+        'ε' => "ει",
+        'ο' => "ου",
+        _ => "υ",
+    };
+    let masculine = match v {
+        'ε' => "εις",
+        'ο' => "ους",
+        'α' => "ας",
+        _ => "υς",
+    };
+
+    Conjugation {
+        indicative: Some([
+            [format!("{l}μι"), format!("{l}ς"), format!("{l}σι|{l}σιν")],
+            ["-".to_string(), format!("{v}τον"), format!("{v}τον")],
+            [format!("{v}μεν"), format!("{v}τε"), third_plural],
+        ]),
+        subjunctive: Some(subjunctive(v, &SUBJUNCTIVE_ACTIVE)),
+        optative: Some(match v {
+            'υ' => prefixed(v, &OPTATIVE_ACTIVE),
+            _ => prefixed(v, &OPTATIVE),
+        }),
+        imperative: Some([
+            ["-".to_string(), imperative.to_string(), format!("{v}τω")],
+            ["-".to_string(), format!("{v}τον"), format!("{v}των")],
+            ["-".to_string(), format!("{v}τε"), format!("{v}ντων")],
+        ]),
+        infinitive: Some((format!("{v}ναι"), Accentuation::Penult)),
+        participle: Some((
+            [
+                masculine.to_string(),
+                format!("{}σα", masculine.trim_end_matches('ς')),
+                format!("{v}ν"),
+                format!("{v}ντος"),
+            ],
+            Accentuation::Oxytone,
+        )),
+        ending_accent: v != 'υ',
+    }
+}
+
+fn imperfect_active(v: char) -> Conjugation<String> {
+    let l = long(v);
+    let singular = match v {
+        'ε' => ["ην".to_string(), "εις".to_string(), "ει".to_string()],
+        'ο' => ["ουν".to_string(), "ους".to_string(), "ου".to_string()],
+        _ => [format!("{l}ν"), format!("{l}ς"), l.to_string()],
+    };
+
+    Conjugation {
+        indicative: Some([
+            singular,
+            ["-".to_string(), format!("{v}τον"), format!("{v}την")],
+            [format!("{v}μεν"), format!("{v}τε"), format!("{v}σαν")],
+        ]),
+        ..Default::default()
+    }
+}
+
+fn present_middle(v: char, ending_accent: bool) -> Conjugation<String> {
+    Conjugation {
+        indicative: Some(prefixed(v, &ATHEMATIC_MIDDLE)),
+        subjunctive: Some(subjunctive(v, &SUBJUNCTIVE_MIDDLE)),
+        optative: Some(match v {
+            'υ' => prefixed(v, &OPTATIVE_MIDDLE),
+            _ => prefixed(v, &OPTATIVE_MIDDLE_ATHEMATIC),
+        }),
+        imperative: Some(prefixed(v, &ATHEMATIC_IMPERATIVE_MIDDLE)),
+        infinitive: Some((format!("{v}σθαι"), Accentuation::Recessive)),
+        participle: Some((
+            ["μενος", "μενη", "μενον", "μενου"].map(|x| format!("{v}{x}")),
+            Accentuation::Recessive,
+        )),
+        ending_accent,
+    }
+}
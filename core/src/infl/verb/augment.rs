@@ -0,0 +1,95 @@
+use unicode_normalization::UnicodeNormalization;
+
+use crate::utils::str::remove_diacritics::remove_diacritics_char;
+
+const SMOOTH_BREATHING: char = '\u{0313}';
+const ROUGH_BREATHING: char = '\u{0314}';
+const IOTA_SUBSCRIPT: char = '\u{0345}';
+
+fn is_vowel(c: char) -> bool {
+    matches!(
+        remove_diacritics_char(c),
+        'α' | 'ε' | 'η' | 'ι' | 'ο' | 'υ' | 'ω'
+    )
+}
+
+fn breathing(c: char) -> Option<char> {
+    c.to_string()
+        .nfd()
+        .find(|x| matches!(*x, SMOOTH_BREATHING | ROUGH_BREATHING))
+}
+
+fn with_marks(letter: char, breathing: Option<char>, subscript: bool) -> String {
+    std::iter::once(letter)
+        .chain(breathing)
+        .chain(subscript.then_some(IOTA_SUBSCRIPT))
+        .nfc()
+        .collect()
+}
+
+/// How the augment changes the start of `stem`: the number of letters it replaces, and what
+/// with. Diphthongs carry their breathing on the second letter, and keep it.
+fn augment_prefix(stem: &str) -> (usize, String) {
+    let mut chars = stem.chars();
+    let Some(first) = chars.next() else {
+        return (0, String::new());
+    };
+    let second = chars.next();
+    let base = remove_diacritics_char(first);
+
+    if base == 'ρ' {
+        return (1, "ἐρρ".to_string());
+    }
+    if !is_vowel(first) {
+        return (0, "ἐ".to_string());
+    }
+
+    if let Some(second) = second {
+        let mark = breathing(second);
+        match (base, remove_diacritics_char(second)) {
+            ('α' | 'ε', 'ι') => return (2, with_marks('η', mark, true)),
+            ('ο', 'ι') => return (2, with_marks('ω', mark, true)),
+            ('α' | 'ε', 'υ') => return (2, format!("η{}", with_marks('υ', mark, false))),
+            ('ο', 'υ') => return (0, String::new()),
+            _ => {}
+        }
+    }
+
+    let mark = breathing(first);
+    match base {
+        'α' | 'ε' => (1, with_marks('η', mark, false)),
+        'ο' => (1, with_marks('ω', mark, false)),
+        _ => (0, String::new()),
+    }
+}
+
+/// Past tense augment: ἐ- before a consonant, doubling ῥ (λυ- → ἐλυ-, ῥιπτ- → ἐρριπτ-), or the
+/// first vowel lengthened (ἀκου- → ἠκου-, οἰκε- → ᾠκε-, αὐξ- → ηὐξ-).
+pub fn augment(stem: &str) -> String {
+    let (replaced, prefix) = augment_prefix(stem);
+    prefix + &stem.chars().skip(replaced).collect::<String>()
+}
+
+/// Pluperfect augment: ἐ- before a reduplicated perfect (λελυκ- → ἐλελυκ-), and none before
+/// one that starts with a vowel, already lengthened (εἰληφ-, ἠκουκ-).
+pub fn augment_pluperfect(stem: &str) -> String {
+    match stem.chars().next() {
+        Some(x) if is_vowel(x) => stem.to_string(),
+        _ => augment(stem),
+    }
+}
+
+/// `form` without the augment `present` would take, or the syllabic one of a suppletive stem
+/// (ἐσθίω, ἐφαγ- → φαγ-), or as it is when it has none: ἐλυσ- → λυσ-, ἠκουσ- → ἀκουσ-, and
+/// εἰπ- stays εἰπ- for λέγω.
+pub fn unaugment(form: &str, present: &str) -> String {
+    let (replaced, prefix) = augment_prefix(present);
+    if let Some(rest) = form.strip_prefix(&prefix).filter(|_| !prefix.is_empty()) {
+        return present.chars().take(replaced).collect::<String>() + rest;
+    }
+
+    match form.strip_prefix('ἐ') {
+        Some(rest) if rest.chars().next().is_some_and(|x| !is_vowel(x)) => rest.to_string(),
+        _ => form.to_string(),
+    }
+}
@@ -0,0 +1,43 @@
+/// Vowels an ending can start with, longest first so that diphthongs win.
+const LEADING: [&str; 9] = ["ει", "ου", "οι", "ε", "η", "ῃ", "ο", "ω", "ῳ"];
+
+/// Attic contraction of a stem's last vowel (α, ε or ο) with its ending: τιμα-ω → τιμω,
+/// ποιε-ει → ποιει, δηλο-ῃς → δηλοις. Endings starting with a consonant are appended as is.
+pub fn contract(stem: &str, ending: &str) -> String {
+    let Some((base, vowel)) = stem.char_indices().last().map(|(i, x)| (&stem[..i], x)) else {
+        return ending.to_string();
+    };
+
+    // The infinitive's ει comes from ε-εν, and contracts as ε + ε.
+    if ending == "ειν" {
+        let contracted = match vowel {
+            'α' => "αν",
+            'ε' => "ειν",
+            'ο' => "ουν",
+            _ => return format!("{stem}{ending}"),
+        };
+        return format!("{base}{contracted}");
+    }
+
+    let Some(leading) = LEADING.iter().find(|x| ending.starts_with(**x)) else {
+        return format!("{stem}{ending}");
+    };
+    let rest = &ending[leading.len()..];
+
+    let contracted = match (vowel, *leading) {
+        ('α', "ε" | "η") => "α",
+        ('α', "ει" | "ῃ") => "ᾳ",
+        ('α', "ο" | "ω" | "ου") => "ω",
+        ('α', "οι" | "ῳ") => "ῳ",
+        ('ε', "ε" | "ει") => "ει",
+        ('ε', "ο" | "ου") => "ου",
+        ('ε', x) => x,
+        ('ο', "ε" | "ο" | "ου") => "ου",
+        ('ο', "η" | "ω") => "ω",
+        ('ο', "ει" | "οι" | "ῃ") => "οι",
+        ('ο', "ῳ") => "ῳ",
+        _ => return format!("{stem}{ending}"),
+    };
+
+    format!("{base}{contracted}{rest}")
+}
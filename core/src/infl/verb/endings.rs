@@ -0,0 +1,264 @@
+use super::{Conjugation, Endings};
+use crate::infl::accent::Accentuation::{self, *};
+
+const EMPTY: Conjugation = Conjugation {
+    indicative: None,
+    subjunctive: None,
+    optative: None,
+    imperative: None,
+    infinitive: None,
+    participle: None,
+    ending_accent: false,
+};
+
+const PRESENT_INDICATIVE_ACTIVE: Endings = [
+    ["ω", "εις", "ει"],
+    ["-", "ετον", "ετον"],
+    ["ομεν", "ετε", "ουσι|ουσιν"],
+];
+const PRESENT_INDICATIVE_MIDDLE: Endings = [
+    ["ομαι", "ῃ|ει", "εται"],
+    ["ομεθον", "εσθον", "εσθον"],
+    ["ομεθα", "εσθε", "ονται"],
+];
+const SECONDARY_ACTIVE: Endings = [
+    ["ον", "ες", "ε|εν"],
+    ["-", "ετον", "ετην"],
+    ["ομεν", "ετε", "ον"],
+];
+const SECONDARY_MIDDLE: Endings = [
+    ["ομην", "ου", "ετο"],
+    ["ομεθον", "εσθον", "εσθην"],
+    ["ομεθα", "εσθε", "οντο"],
+];
+pub const SUBJUNCTIVE_ACTIVE: Endings = [
+    ["ω", "ῃς", "ῃ"],
+    ["-", "ητον", "ητον"],
+    ["ωμεν", "ητε", "ωσι|ωσιν"],
+];
+pub const SUBJUNCTIVE_MIDDLE: Endings = [
+    ["ωμαι", "ῃ", "ηται"],
+    ["ωμεθον", "ησθον", "ησθον"],
+    ["ωμεθα", "ησθε", "ωνται"],
+];
+pub const OPTATIVE_ACTIVE: Endings = [
+    ["οιμι", "οις", "οι"],
+    ["-", "οιτον", "οιτην"],
+    ["οιμεν", "οιτε", "οιεν"],
+];
+/// Contract verbs mostly take -οιην in the singular: τιμῴην, ποιοίην, δηλοίην.
+const OPTATIVE_ACTIVE_CONTRACT: Endings = [
+    ["οιην|οιμι", "οιης|οις", "οιη|οι"],
+    ["-", "οιτον", "οιτην"],
+    ["οιμεν", "οιτε", "οιεν"],
+];
+pub const OPTATIVE_MIDDLE: Endings = [
+    ["οιμην", "οιο", "οιτο"],
+    ["οιμεθον", "οισθον", "οισθην"],
+    ["οιμεθα", "οισθε", "οιντο"],
+];
+const IMPERATIVE_ACTIVE: Endings = [
+    ["-", "ε", "ετω"],
+    ["-", "ετον", "ετων"],
+    ["-", "ετε", "οντων"],
+];
+const IMPERATIVE_MIDDLE: Endings = [
+    ["-", "ου", "εσθω"],
+    ["-", "εσθον", "εσθων"],
+    ["-", "εσθε", "εσθων"],
+];
+
+/// Endings of athematic presents, perfect middles and root aorists, which take no thematic
+/// vowel.
+pub const ATHEMATIC_MIDDLE: Endings = [
+    ["μαι", "σαι", "ται"],
+    ["μεθον", "σθον", "σθον"],
+    ["μεθα", "σθε", "νται"],
+];
+pub const ATHEMATIC_SECONDARY_MIDDLE: Endings = [
+    ["μην", "σο", "το"],
+    ["μεθον", "σθον", "σθην"],
+    ["μεθα", "σθε", "ντο"],
+];
+pub const ATHEMATIC_IMPERATIVE_MIDDLE: Endings = [
+    ["-", "σο", "σθω"],
+    ["-", "σθον", "σθων"],
+    ["-", "σθε", "σθων"],
+];
+
+const THEMATIC_PARTICIPLE: ([&str; 4], Accentuation) = (["ων", "ουσα", "ον", "οντος"], Recessive);
+const THEMATIC_PARTICIPLE_MIDDLE: ([&str; 4], Accentuation) =
+    (["ομενος", "ομενη", "ομενον", "ομενου"], Recessive);
+
+pub const PRESENT_ACTIVE: Conjugation = Conjugation {
+    indicative: Some(PRESENT_INDICATIVE_ACTIVE),
+    subjunctive: Some(SUBJUNCTIVE_ACTIVE),
+    optative: Some(OPTATIVE_ACTIVE),
+    imperative: Some(IMPERATIVE_ACTIVE),
+    infinitive: Some(("ειν", Recessive)),
+    participle: Some(THEMATIC_PARTICIPLE),
+    ending_accent: false,
+};
+pub const PRESENT_ACTIVE_CONTRACT: Conjugation = Conjugation {
+    optative: Some(OPTATIVE_ACTIVE_CONTRACT),
+    ..PRESENT_ACTIVE
+};
+pub const PRESENT_MIDDLE: Conjugation = Conjugation {
+    indicative: Some(PRESENT_INDICATIVE_MIDDLE),
+    subjunctive: Some(SUBJUNCTIVE_MIDDLE),
+    optative: Some(OPTATIVE_MIDDLE),
+    imperative: Some(IMPERATIVE_MIDDLE),
+    infinitive: Some(("εσθαι", Recessive)),
+    participle: Some(THEMATIC_PARTICIPLE_MIDDLE),
+    ending_accent: false,
+};
+
+pub const IMPERFECT_ACTIVE: Conjugation = Conjugation {
+    indicative: Some(SECONDARY_ACTIVE),
+    ..EMPTY
+};
+pub const IMPERFECT_MIDDLE: Conjugation = Conjugation {
+    indicative: Some(SECONDARY_MIDDLE),
+    ..EMPTY
+};
+
+pub const FUTURE_ACTIVE: Conjugation = Conjugation {
+    subjunctive: None,
+    imperative: None,
+    ..PRESENT_ACTIVE
+};
+pub const FUTURE_MIDDLE: Conjugation = Conjugation {
+    subjunctive: None,
+    imperative: None,
+    ..PRESENT_MIDDLE
+};
+
+pub const FIRST_AORIST_ACTIVE: Conjugation = Conjugation {
+    indicative: Some([
+        ["α", "ας", "ε|εν"],
+        ["-", "ατον", "ατην"],
+        ["αμεν", "ατε", "αν"],
+    ]),
+    subjunctive: Some(SUBJUNCTIVE_ACTIVE),
+    optative: Some([
+        ["αιμι", "αις|ειας", "αι|ειε|ειεν"],
+        ["-", "αιτον", "αιτην"],
+        ["αιμεν", "αιτε", "αιεν|ειαν"],
+    ]),
+    imperative: Some([
+        ["-", "ον", "ατω"],
+        ["-", "ατον", "ατων"],
+        ["-", "ατε", "αντων"],
+    ]),
+    infinitive: Some(("αι", Penult)),
+    // The α is long in the masculine and short in the neuter: λύσᾱς, λῦσᾰν.
+    participle: Some((["ᾱς", "ασα", "ᾰν", "αντος"], Recessive)),
+    ending_accent: false,
+};
+pub const FIRST_AORIST_MIDDLE: Conjugation = Conjugation {
+    indicative: Some([
+        ["αμην", "ω", "ατο"],
+        ["αμεθον", "ασθον", "ασθην"],
+        ["αμεθα", "ασθε", "αντο"],
+    ]),
+    subjunctive: Some(SUBJUNCTIVE_MIDDLE),
+    optative: Some([
+        ["αιμην", "αιο", "αιτο"],
+        ["αιμεθον", "αισθον", "αισθην"],
+        ["αιμεθα", "αισθε", "αιντο"],
+    ]),
+    imperative: Some([
+        ["-", "αι", "ασθω"],
+        ["-", "ασθον", "ασθων"],
+        ["-", "ασθε", "ασθων"],
+    ]),
+    infinitive: Some(("ασθαι", Recessive)),
+    participle: Some((["αμενος", "αμενη", "αμενον", "αμενου"], Recessive)),
+    ending_accent: false,
+};
+
+/// The 2nd aorist accents its infinitives and active participle on the ending: λαβεῖν,
+/// λαβέσθαι, λαβών.
+pub const SECOND_AORIST_ACTIVE: Conjugation = Conjugation {
+    indicative: Some(SECONDARY_ACTIVE),
+    infinitive: Some(("ειν", Perispomenon)),
+    participle: Some((THEMATIC_PARTICIPLE.0, Oxytone)),
+    ..PRESENT_ACTIVE
+};
+pub const SECOND_AORIST_MIDDLE: Conjugation = Conjugation {
+    indicative: Some(SECONDARY_MIDDLE),
+    infinitive: Some(("εσθαι", Penult)),
+    ..PRESENT_MIDDLE
+};
+/// Athematic aorists like ἔβην and ἔγνων, of which only the indicative and infinitive are
+/// regular enough to generate.
+pub const ROOT_AORIST: Conjugation = Conjugation {
+    indicative: Some([["ν", "ς", ""], ["-", "τον", "την"], ["μεν", "τε", "σαν"]]),
+    infinitive: Some(("ναι", Penult)),
+    ..EMPTY
+};
+
+/// The subjunctive and optative keep the accent of the -ε- they contract with: λυθῶ, λυθείην.
+pub const AORIST_PASSIVE: Conjugation = Conjugation {
+    indicative: Some([
+        ["ην", "ης", "η"],
+        ["-", "ητον", "ητην"],
+        ["ημεν", "ητε", "ησαν"],
+    ]),
+    subjunctive: Some(SUBJUNCTIVE_ACTIVE),
+    optative: Some([
+        ["ειην", "ειης", "ειη"],
+        ["-", "ειητον|ειτον", "ειητην|ειτην"],
+        ["ειημεν|ειμεν", "ειητε|ειτε", "ειησαν|ειεν"],
+    ]),
+    imperative: Some([
+        ["-", "ητι", "ητω"],
+        ["-", "ητον", "ητων"],
+        ["-", "ητε", "εντων"],
+    ]),
+    infinitive: Some(("ηναι", Penult)),
+    participle: Some((["εις", "εισα", "εν", "εντος"], Oxytone)),
+    ending_accent: true,
+};
+/// Without the θ of the 1st aorist passive, the imperative keeps its -θι: γράφηθι.
+pub const SECOND_AORIST_PASSIVE: Conjugation = Conjugation {
+    imperative: Some([
+        ["-", "ηθι", "ητω"],
+        ["-", "ητον", "ητων"],
+        ["-", "ητε", "εντων"],
+    ]),
+    ..AORIST_PASSIVE
+};
+
+pub const PERFECT_ACTIVE: Conjugation = Conjugation {
+    indicative: Some([
+        ["α", "ας", "ε|εν"],
+        ["-", "ατον", "ατον"],
+        ["αμεν", "ατε", "ασι|ασιν"],
+    ]),
+    subjunctive: Some(SUBJUNCTIVE_ACTIVE),
+    optative: Some(OPTATIVE_ACTIVE),
+    imperative: None,
+    infinitive: Some(("εναι", Penult)),
+    participle: Some((["ως", "υια", "ος", "οτος"], Oxytone)),
+    ending_accent: false,
+};
+pub const PERFECT_MIDDLE: Conjugation = Conjugation {
+    indicative: Some(ATHEMATIC_MIDDLE),
+    imperative: Some(ATHEMATIC_IMPERATIVE_MIDDLE),
+    infinitive: Some(("σθαι", Penult)),
+    participle: Some((["μενος", "μενη", "μενον", "μενου"], Penult)),
+    ..EMPTY
+};
+pub const PLUPERFECT_ACTIVE: Conjugation = Conjugation {
+    indicative: Some([
+        ["η|ειν", "ης|εις", "ει|ειν"],
+        ["-", "ετον", "ετην"],
+        ["εμεν|ειμεν", "ετε|ειτε", "εσαν|εισαν"],
+    ]),
+    ..EMPTY
+};
+pub const PLUPERFECT_MIDDLE: Conjugation = Conjugation {
+    indicative: Some(ATHEMATIC_SECONDARY_MIDDLE),
+    ..EMPTY
+};
@@ -0,0 +1,66 @@
+use crate::{
+    api::lexicon::lexicon_model::{NounInflectionGenders, NounInflectionNumbers},
+    error::SafeError,
+    grammar::{Declension, DeclensionType, Gender, PartOfSpeech},
+    infl::{
+        accent,
+        noun::{self, decline, Endings, Stems},
+    },
+};
+
+const DUAL: Endings = ["ᾱ", "αιν", "αιν", "ᾱ", "ᾱ"];
+const PLURAL: Endings = ["αι", "ων", "αις", "ᾱς", "αι"];
+
+/// Declines a participle from its masculine, feminine and neuter nominatives and its masculine
+/// genitive singular: λύων, λύουσα, λῦον, λύοντος. -μενος participles go by the 2nd
+/// declension, the others by the 3rd, and their feminines by the 1st. Only the masculine needs
+/// its accent, which the other genders keep.
+pub fn decline_participle(
+    [masculine, feminine, neuter, genitive]: [String; 4],
+) -> Result<NounInflectionGenders, SafeError> {
+    let decl_type = match masculine.ends_with("ος") {
+        true => DeclensionType::Second,
+        false => DeclensionType::Third,
+    };
+    let declension = |gender| Declension {
+        gender: Some(gender),
+        decl_type: Some(decl_type),
+        ..Declension::partial_default(PartOfSpeech::Verb)
+    };
+
+    // The α of the feminine is short but for λελυκυῖα, which has it on the penult anyway.
+    let feminine = accent::like(&feminine, &masculine, feminine.ends_with('α'));
+    let neuter = accent::like(&neuter, &masculine, false);
+
+    let masculine = noun::inflect(&masculine, Some(&genitive), &declension(Gender::Masculine))?;
+    let neuter = noun::inflect(&neuter, Some(&genitive), &declension(Gender::Neuter))?;
+
+    Ok(NounInflectionGenders {
+        masculine: masculine.masculine,
+        feminine: Some(*decline_feminine(&feminine)?),
+        neuter: neuter.neuter,
+    })
+}
+
+/// λυομένη keeps its η, λύουσα has a short α and λελυκυῖα a pure one.
+fn decline_feminine(lemma: &str) -> Result<Box<NounInflectionNumbers>, SafeError> {
+    let feminine = accent::strip(lemma);
+    let (stem, singular) = if let Some(stem) = feminine.strip_suffix('η') {
+        (stem, ["η", "ης", "ῃ", "ην", "η"])
+    } else if let Some(stem) = feminine.strip_suffix('α') {
+        match stem.ends_with('ι') {
+            true => (stem, ["α", "ᾱς", "ᾳ", "αν", "α"]),
+            false => (stem, ["α", "ης", "ῃ", "αν", "α"]),
+        }
+    } else {
+        return Err(format!("could not match participle feminine {feminine}").into());
+    };
+
+    let stems = Stems {
+        short: stem,
+        long: stem,
+    };
+    let mut numbers = decline(&stems, singular, DUAL, PLURAL);
+    accent::accent_noun(&mut numbers, lemma, Some(DeclensionType::First));
+    Ok(numbers)
+}
@@ -1,9 +1,12 @@
+use clap::Parser;
 use error::SafeError;
 
 mod api;
 mod borrow;
+mod cli;
 mod config;
 mod error;
+mod export;
 mod grammar;
 mod infl;
 mod log;
@@ -14,16 +17,11 @@ mod scrappers;
 mod task;
 mod texts;
 mod utils;
+mod validation;
 
 #[tokio::main]
 async fn main() -> Result<(), SafeError> {
     log::init()?;
 
-    // scrappers::abarim::import().await?;
-    // scrappers::katabiblon::import().await?;
-    scrappers::wiki::import().await?;
-
-    api::init().await?;
-
-    Ok(())
+    cli::run(cli::Cli::parse()).await
 }
@@ -29,7 +29,7 @@ fn get_redis_key(key: &str) -> String {
 
 pub async fn set(key: &str, value: &str) -> Result<(), SafeError> {
     let mut conn = get_redis().await?;
-    conn.set(get_redis_key(key), value).await?;
+    conn.set::<_, _, ()>(get_redis_key(key), value).await?;
     Ok(())
 }
 
@@ -42,3 +42,9 @@ pub async fn get(key: &str) -> Result<Option<String>, SafeError> {
         _ => None,
     })
 }
+
+pub async fn del(key: &str) -> Result<(), SafeError> {
+    let mut conn = get_redis().await?;
+    conn.del::<_, ()>(get_redis_key(key)).await?;
+    Ok(())
+}
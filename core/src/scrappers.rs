@@ -1,3 +1,4 @@
 pub mod abarim;
 pub mod katabiblon;
+pub mod morphgnt;
 pub mod wiki;
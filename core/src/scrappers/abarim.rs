@@ -1,25 +1,77 @@
-use tracing::log::info;
+use strum::IntoEnumIterator;
+use tracing::{error, info};
 
-use crate::{api::verse::verse_repo::VerseRepo, error::SafeError, persistence, texts::Book};
+use crate::{api::verse::verse_repo::VerseRepo, error::SafeError, texts::Book};
 
 pub mod declension;
 pub mod parser;
 
-#[allow(dead_code)]
-pub async fn import() -> Result<(), SafeError> {
-    let parsed = parser::parse_chapter(1, Book::Matthew).await?;
+struct FailedChapter {
+    book: Book,
+    chapter: u8,
+    error: SafeError,
+}
+
+pub async fn import(book: Option<Book>, chapter: Option<u8>) -> Result<(), SafeError> {
+    let chapters = Book::iter()
+        .filter(|b| book.is_none_or(|book| book == *b))
+        .flat_map(|b| (1..=b.chapter_count()).map(move |c| (b, c)))
+        .filter(|(_, c)| chapter.is_none_or(|chapter| chapter == *c))
+        .collect::<Vec<_>>();
 
-    persistence::get_db()
-        .await?
-        .collection(VerseRepo::COLLECTION_NAME)
-        .insert_many(parsed.verses.to_owned(), None)
-        .await?;
+    let mut failures = Vec::<FailedChapter>::new();
+    let mut imported = 0;
+
+    for (i, (book, chapter)) in chapters.iter().copied().enumerate() {
+        match import_chapter(book, chapter).await {
+            Ok(count) => {
+                imported += count;
+                info!(
+                    "[{}/{}] {} verses of {book} {chapter} imported into {}",
+                    i + 1,
+                    chapters.len(),
+                    count,
+                    VerseRepo::COLLECTION_NAME
+                );
+            }
+            Err(e) => {
+                error!(
+                    "[{}/{}] {book} {chapter} failed: {e}",
+                    i + 1,
+                    chapters.len()
+                );
+                failures.push(FailedChapter {
+                    book,
+                    chapter,
+                    error: e,
+                });
+            }
+        }
+    }
 
     info!(
-        "{} verses imported into {}",
-        parsed.verses.len(),
-        VerseRepo::COLLECTION_NAME
+        "{} verses imported from {} chapters, {} failed",
+        imported,
+        chapters.len() - failures.len(),
+        failures.len()
     );
 
+    if !failures.is_empty() {
+        for failure in &failures {
+            error!("{} {}: {}", failure.book, failure.chapter, failure.error);
+        }
+        return Err(format!("{} chapters failed to import", failures.len()).into());
+    }
+
     Ok(())
 }
+
+async fn import_chapter(book: Book, chapter: u8) -> Result<usize, SafeError> {
+    let parsed = parser::parse_chapter(chapter, book).await?;
+
+    for verse in &parsed.verses {
+        VerseRepo::update_one(verse).await?;
+    }
+
+    Ok(parsed.verses.len())
+}
@@ -47,10 +47,21 @@ pub async fn parse_chapter(chapter: u8, book: Book) -> Result<ParsedChapter, Saf
 fn get_url(base: &str, book: Book, chapter: u8) -> String {
     format!(
         "{base}/{b}/{b}-{chapter}-parsed.html",
-        b = book.to_string().capitalize()
+        b = get_book_name(book)
     )
 }
 
+// abarim puts the number of numbered books first, e.g. 1-Corinthians
+fn get_book_name(book: Book) -> String {
+    let name = book.to_string();
+    match name.chars().last() {
+        Some(n) if n.is_ascii_digit() => {
+            format!("{n}-{}", name.trim_end_matches(n).to_string().capitalize())
+        }
+        _ => name.capitalize(),
+    }
+}
+
 fn get_verse_translation(verse_number: u8, dom: &tl::VDom) -> Option<String> {
     let parser = dom.parser();
     let verse_selector = &format!("[id*='KJV-AVerse-{verse_number}']");
@@ -162,7 +173,7 @@ fn parse_word(
         .children()
         .all(word_dom.parser())
         .iter()
-        .filter(|d| d.inner_text(word_dom.parser()) != "")
+        .filter(|d| !d.inner_text(word_dom.parser()).is_empty())
         .map(|e| e.inner_text(word_dom.parser()).to_string())
         .collect();
 
@@ -178,5 +189,6 @@ fn parse_word(
         language: Language::Greek.lang_code(),
         translation: HashMap::from([(Language::English.lang_code(), english.to_string())]),
         declension,
+        lemma: None,
     })
 }
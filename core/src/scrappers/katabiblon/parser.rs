@@ -22,8 +22,10 @@ pub async fn parse_word(
         PartOfSpeech::Noun(_) => match details.declension.decl_type {
             Some(DeclensionType::Indeclinable) | None => Vec::new(),
             _ => vec![WordInflection {
+                part_of_speech: Some(details.declension.part_of_speech),
                 noun: Some(Box::from(infl::noun::inflect(
                     &details.lemma,
+                    None,
                     &details.declension,
                 )?)),
                 ..Default::default()
@@ -42,6 +44,7 @@ pub async fn parse_word(
 
     Ok(LexiconEntry {
         lemma: details.lemma,
+        lemma_key: None,
         inflections,
         definitions,
     })
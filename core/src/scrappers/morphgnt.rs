@@ -0,0 +1,101 @@
+use std::{fs, path::Path};
+
+use tracing::{error, info};
+
+use crate::{
+    api::verse::{
+        verse_model::VerseFilter,
+        verse_repo::{self, VerseRepo},
+    },
+    error::SafeError,
+    texts::Book,
+};
+
+pub mod parser;
+
+struct FailedFile {
+    name: String,
+    error: SafeError,
+}
+
+/// Imports the MorphGNT files of `dir` (e.g. `61-Mt-morphgnt.txt`), replacing the words of the
+/// verses they cover. Verse translations already imported are kept, and lines that can't be
+/// parsed are reported once every file is imported.
+pub async fn import(dir: &Path, book: Option<Book>) -> Result<(), SafeError> {
+    verse_repo::configure().await?;
+
+    let mut files = fs::read_dir(dir)?
+        .map(|entry| entry.map(|x| x.path()))
+        .collect::<Result<Vec<_>, _>>()?;
+    files.retain(|x| x.extension().is_some_and(|ext| ext == "txt"));
+    files.sort();
+
+    let mut failures = Vec::<FailedFile>::new();
+    let mut failed_lines = 0;
+    let mut imported = 0;
+
+    for (i, file) in files.iter().enumerate() {
+        let name = file.display().to_string();
+        match import_file(file, book).await {
+            Ok((count, errors)) => {
+                imported += count;
+                for line in &errors {
+                    error!("{name}:{}: {}", line.number, line.error);
+                }
+                failed_lines += errors.len();
+                info!(
+                    "[{}/{}] {} verses of {name} imported into {}",
+                    i + 1,
+                    files.len(),
+                    count,
+                    VerseRepo::COLLECTION_NAME
+                );
+            }
+            Err(e) => {
+                error!("[{}/{}] {name} failed: {e}", i + 1, files.len());
+                failures.push(FailedFile { name, error: e });
+            }
+        }
+    }
+
+    info!(
+        "{} verses imported from {} files, {} failed, {} lines skipped",
+        imported,
+        files.len() - failures.len(),
+        failures.len(),
+        failed_lines
+    );
+
+    if !failures.is_empty() {
+        for failure in &failures {
+            error!("{}: {}", failure.name, failure.error);
+        }
+        return Err(format!("{} files failed to import", failures.len()).into());
+    }
+    if failed_lines > 0 {
+        return Err(format!("{failed_lines} lines failed to import").into());
+    }
+
+    Ok(())
+}
+
+async fn import_file(
+    path: &Path,
+    book: Option<Book>,
+) -> Result<(usize, Vec<parser::FailedLine>), SafeError> {
+    let parsed = parser::parse_file(&fs::read_to_string(path)?);
+    let mut count = 0;
+
+    for mut verse in parsed.verses {
+        if book.is_some_and(|book| book != verse.book) {
+            continue;
+        }
+        if let Some(existing) = VerseRepo::find_one(&VerseFilter::from(&verse)).await? {
+            verse.translation = existing.translation;
+        }
+        VerseRepo::update_one(&verse).await?;
+        count += 1;
+    }
+
+    Ok((count, parsed.errors))
+}
@@ -0,0 +1,177 @@
+use std::collections::HashMap;
+
+use strum::IntoEnumIterator;
+
+use crate::{
+    error::SafeError,
+    grammar::{Declension, Language, Verse, Word},
+    texts::{Book, Collection},
+};
+
+/// One line of a MorphGNT file: `BBCCVV pos parse text word normalized lemma`.
+#[derive(Debug)]
+struct Line<'a> {
+    book: Book,
+    chapter_number: u8,
+    verse_number: u8,
+    pos: &'a str,
+    parse: &'a str,
+    word: &'a str,
+    lemma: &'a str,
+}
+
+pub struct FailedLine {
+    pub number: usize,
+    pub error: SafeError,
+}
+
+pub struct ParsedFile {
+    pub verses: Vec<Verse>,
+    pub errors: Vec<FailedLine>,
+}
+
+/// Verses of a MorphGNT file, in the file's order. Lines that can't be parsed are left out of
+/// their verse and listed in the errors.
+pub fn parse_file(content: &str) -> ParsedFile {
+    let mut verses: Vec<Verse> = vec![];
+    let mut errors = Vec::<FailedLine>::new();
+
+    for (line_i, line) in content.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let parsed = parse_line(line).and_then(|line| {
+            let declension = Declension::from_morphgnt(&format!("{} {}", line.pos, line.parse))?;
+            Ok((line, declension))
+        });
+        let (line, declension) = match parsed {
+            Ok(x) => x,
+            Err(error) => {
+                errors.push(FailedLine {
+                    number: line_i + 1,
+                    error,
+                });
+                continue;
+            }
+        };
+
+        let word = Word {
+            language: Language::Greek.lang_code(),
+            text: line.word.to_string(),
+            translation: HashMap::new(),
+            declension,
+            lemma: Some(line.lemma.to_string()),
+        };
+
+        match verses.last_mut() {
+            Some(verse)
+                if verse.book == line.book
+                    && verse.chapter_number == line.chapter_number
+                    && verse.verse_number == line.verse_number =>
+            {
+                verse.words.push(word)
+            }
+            _ => verses.push(Verse {
+                collection: Collection::NewTestament,
+                book: line.book,
+                chapter_number: line.chapter_number,
+                verse_number: line.verse_number,
+                translation: HashMap::new(),
+                words: vec![word],
+            }),
+        }
+    }
+
+    ParsedFile { verses, errors }
+}
+
+fn parse_line(line: &str) -> Result<Line<'_>, SafeError> {
+    let columns = line.split_whitespace().collect::<Vec<_>>();
+    let [reference, pos, parse, _text, word, _normalized, lemma] = columns[..] else {
+        return Err(format!("expected 7 columns, got {}", columns.len()).into());
+    };
+
+    let number = |range: std::ops::Range<usize>| -> Result<u8, SafeError> {
+        reference
+            .get(range)
+            .and_then(|x| x.parse().ok())
+            .ok_or_else(|| format!("invalid reference {reference}").into())
+    };
+    if reference.len() != 6 {
+        return Err(format!("invalid reference {reference}").into());
+    }
+    let book_number = number(0..2)?;
+    let book = (book_number as usize)
+        .checked_sub(1)
+        .and_then(|i| Book::iter().nth(i))
+        .ok_or_else(|| format!("unknown book number {book_number}"))?;
+
+    Ok(Line {
+        book,
+        chapter_number: number(2..4)?,
+        verse_number: number(4..6)?,
+        pos,
+        parse,
+        word,
+        lemma,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FILE: &str = "\
+010101 N- ----NSF- Βίβλος Βίβλος βίβλος βίβλος
+010101 N- ----GSF- γενέσεως γενέσεως γενέσεως γένεσις
+010102 V- 3AAI-S-- ἐγέννησεν ἐγέννησεν ἐγέννησε(ν) γεννάω
+";
+
+    #[test]
+    fn parses_lines() {
+        let line = parse_line("270203 RA ----NSM- ὁ ὁ ὁ ὁ").unwrap();
+        assert_eq!(line.book, Book::Revelation);
+        assert_eq!((line.chapter_number, line.verse_number), (2, 3));
+        assert_eq!((line.pos, line.parse), ("RA", "----NSM-"));
+        assert_eq!((line.word, line.lemma), ("ὁ", "ὁ"));
+
+        assert_eq!(
+            parse_line("010101 N- ----NSF- Βίβλος")
+                .unwrap_err()
+                .to_string(),
+            "expected 7 columns, got 4"
+        );
+        assert!(parse_line("0101 N- ----NSF- Βίβλος Βίβλος βίβλος βίβλος").is_err());
+        assert!(parse_line("000101 N- ----NSF- Βίβλος Βίβλος βίβλος βίβλος").is_err());
+        assert!(parse_line("280101 N- ----NSF- Βίβλος Βίβλος βίβλος βίβλος").is_err());
+    }
+
+    #[test]
+    fn groups_words_into_verses() {
+        let parsed = parse_file(FILE);
+        assert!(parsed.errors.is_empty());
+
+        let verses = parsed.verses;
+        assert_eq!(verses.len(), 2);
+        assert_eq!(verses[0].book, Book::Matthew);
+        assert_eq!(verses[0].verse_number, 1);
+        let words = verses[0]
+            .words
+            .iter()
+            .map(|x| x.text.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(words, ["Βίβλος", "γενέσεως"]);
+        assert_eq!(verses[1].words[0].lemma.as_deref(), Some("γεννάω"));
+    }
+
+    #[test]
+    fn collects_line_errors() {
+        let content = FILE.replace("3AAI-S--", "3ZAI-S--") + "010103 C- -------- καὶ\n";
+        let parsed = parse_file(&content);
+
+        let lines = parsed.errors.iter().map(|x| x.number).collect::<Vec<_>>();
+        assert_eq!(lines, [3, 4]);
+        assert_eq!(parsed.verses.len(), 1);
+        assert_eq!(parsed.verses[0].words.len(), 2);
+    }
+}
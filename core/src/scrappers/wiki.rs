@@ -1,4 +1,4 @@
-use anyhow::Context;
+use std::collections::HashMap;
 
 use tracing::{debug, info, warn};
 
@@ -8,19 +8,29 @@ use crate::{
             lexicon_model::{LexiconEntry, LexiconFilter, LexiconFilterInflection},
             lexicon_repo::LexiconRepo,
         },
-        verse::{verse_model::VerseFilter, verse_repo::VerseRepo},
+        review::{
+            review_model::{PendingReview, ReviewReason},
+            review_repo,
+            review_service::ReviewService,
+        },
+        verse::{
+            verse_model::{VerseFilter, VerseRange, VerseRef},
+            verse_repo::VerseRepo,
+        },
     },
     borrow::Cow,
     error::SafeError,
     grammar::{Declension, DeclensionType, PartOfSpeech, Verse, Word},
     scrappers::{
         katabiblon,
-        wiki::{details::SearchMode, errors::ParseWordError},
-    },
-    utils::str::{
-        closest::closest,
-        remove_diacritics::{remove_diacritics},
+        wiki::{
+            details::SearchMode,
+            errors::ParseWordError,
+            progress::{ImportProgress, ImportProgressRepo},
+        },
     },
+    texts::{Book, Collection},
+    utils::str::{closest::closest, remove_diacritics::remove_diacritics},
 };
 
 mod adjective;
@@ -36,94 +46,190 @@ mod parser;
 mod participle;
 mod particle;
 mod preposition;
+mod progress;
 mod pronoun;
 mod quantifier;
 mod table;
 mod verb;
 
-#[allow(dead_code)]
-pub async fn import() -> Result<(), SafeError> {
-    let mut verse = VerseRepo::find_one(&VerseFilter {
-        collection: Some("new_testament".to_string()),
-        book: Some("matthew".to_string()),
-        chapter_number: Some(1),
-        verse_number: Some(18),
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ImportMode {
+    Interactive,
+    Queue,
+}
+
+pub async fn import(
+    collection: Collection,
+    book: Book,
+    range: VerseRange,
+    mode: ImportMode,
+    restart: bool,
+) -> Result<(), SafeError> {
+    review_repo::configure().await?;
+
+    let key = format!(
+        "lexicon:{collection}:{book}:{}:{}-{}:{}",
+        range.start.chapter_number,
+        range.start.verse_number,
+        range.end.chapter_number,
+        range.end.verse_number
+    );
+    let mut progress = match ImportProgressRepo::find_one(&key).await? {
+        Some(progress) if !restart => progress,
+        _ => ImportProgress::new(key),
+    };
+    if progress.completed {
+        info!(
+            "{} already completed, use --restart to run it again",
+            progress.key
+        );
+        return Ok(());
+    }
+    if let Some(last) = progress.last_verse {
+        info!(
+            "resuming {} after {}:{}",
+            progress.key, last.chapter_number, last.verse_number
+        );
+    }
+
+    let verses = VerseRepo::find_many(&VerseFilter {
+        collection: Some(collection.to_string()),
+        book: Some(book.to_string()),
+        range: Some(range),
+        ..Default::default()
     })
-    .await?
-    .context("no verse")?;
-
-    async fn find_in_lexicon(
-        word: &str,
-        declension: &Declension,
-    ) -> Result<Option<LexiconEntry>, SafeError> {
-        if declension.decl_type == Some(DeclensionType::Indeclinable)
-            || declension.part_of_speech == PartOfSpeech::Particle
-        {
-            return LexiconRepo::find_one(LexiconFilter {
-                lemma: Some(word.to_owned()),
-                ..Default::default()
-            })
-            .await;
+    .await?;
+
+    let mut resolved = HashMap::<(String, Declension), ResolvedWord>::new();
+    for mut verse in verses {
+        let verse_ref = VerseRef {
+            chapter_number: verse.chapter_number,
+            verse_number: verse.verse_number,
+        };
+        if progress.last_verse.is_some_and(|last| verse_ref <= last) {
+            continue;
         }
 
-        LexiconRepo::find_one(LexiconFilter {
-            inflection: Some(LexiconFilterInflection {
-                declension: declension.to_owned(),
-                word: word.to_string(),
-            }),
+        info!(
+            "importing words of {} {}:{}",
+            verse.book, verse.chapter_number, verse.verse_number
+        );
+        import_verse(&mut verse, mode, &mut resolved).await?;
+
+        progress.last_verse = Some(verse_ref);
+        ImportProgressRepo::update_one(&progress).await?;
+    }
+
+    progress.completed = true;
+    ImportProgressRepo::update_one(&progress).await?;
+
+    Ok(())
+}
+
+struct ResolvedWord {
+    entry: LexiconEntry,
+    declension: Declension,
+}
+
+async fn find_in_lexicon(
+    word: &str,
+    declension: &Declension,
+) -> Result<Option<LexiconEntry>, SafeError> {
+    if declension.decl_type == Some(DeclensionType::Indeclinable)
+        || declension.part_of_speech == PartOfSpeech::Particle
+    {
+        return LexiconRepo::find_one(LexiconFilter {
+            lemma: Some(word.to_owned()),
             ..Default::default()
         })
-        .await
+        .await;
     }
 
-    async fn update_word(verse: &mut Verse, word: &Word, index: usize) -> Result<(), SafeError> {
-        let old = verse.words[index].clone();
-        if old.text == word.text {
-            return Ok(());
-        }
-        let requires_confirm = remove_diacritics(&old.text) != remove_diacritics(&word.text);
-        if requires_confirm {
-            let confirmed = cliclack::confirm(format!(
-                "change {} -> {} at word #{index} of verse {}:{}:{}?\n  '{}'",
-                old.text,
-                word.text,
-                verse.book,
-                verse.chapter_number,
-                verse.verse_number,
-                verse
-                    .words
-                    .iter()
-                    .map(|w| w.text.clone())
-                    .collect::<Vec<String>>()
-                    .join(" ")
-            ))
-            .initial_value(true)
-            .interact()?;
-            if !confirmed {
-                return Ok(());
-            }
-        }
+    LexiconRepo::find_one(LexiconFilter {
+        inflection: Some(LexiconFilterInflection {
+            declension: declension.to_owned(),
+            word: word.to_string(),
+        }),
+        ..Default::default()
+    })
+    .await
+}
 
-        verse.words[index] = word.clone();
-        VerseRepo::update_one(verse).await?;
-        debug!(
-            "updated verse {} {} {} {} word {} '{}'",
-            verse.collection,
+/// Writes `word` at `index` of `verse`. A text change beyond diacritics needs confirming, or a
+/// review in queue mode, in which case the rest of the word is written and its text waits for
+/// the review.
+async fn update_word(
+    verse: &mut Verse,
+    word: &Word,
+    index: usize,
+    mode: ImportMode,
+) -> Result<(), SafeError> {
+    let old = verse.words[index].clone();
+    let mut update = word.clone();
+    let requires_confirm =
+        old.text != word.text && remove_diacritics(&old.text) != remove_diacritics(&word.text);
+    if requires_confirm && mode == ImportMode::Queue {
+        let review = PendingReview::new(verse, index, &word.text, ReviewReason::TextChange);
+        ReviewService::submit(&review).await?;
+        update.text = old.text.clone();
+    } else if requires_confirm {
+        let confirmed = cliclack::confirm(format!(
+            "change {} -> {} ({:#}) at word #{index} of verse {}:{}:{}?\n  '{}'",
+            old.text,
+            word.text,
+            word.declension,
             verse.book,
             verse.chapter_number,
             verse.verse_number,
-            index,
-            word.text
-        );
-        Ok(())
+            verse
+                .words
+                .iter()
+                .map(|w| w.text.clone())
+                .collect::<Vec<String>>()
+                .join(" ")
+        ))
+        .initial_value(true)
+        .interact()?;
+        if !confirmed {
+            update.text = old.text.clone();
+        }
+    }
+    if update.text == old.text && update.declension == old.declension {
+        return Ok(());
     }
 
+    verse.words[index] = update;
+    VerseRepo::update_one(verse).await?;
+    debug!(
+        "updated verse {} {} {} {} word {} '{}'",
+        verse.collection,
+        verse.book,
+        verse.chapter_number,
+        verse.verse_number,
+        index,
+        verse.words[index].text
+    );
+    Ok(())
+}
+
+async fn import_verse(
+    verse: &mut Verse,
+    mode: ImportMode,
+    resolved: &mut HashMap<(String, Declension), ResolvedWord>,
+) -> Result<(), SafeError> {
     for (word_i, word) in &mut verse.words.clone().iter_mut().enumerate() {
         debug!("processing #{word_i} word {}", word.text);
 
+        let resolved_key = (word.text.clone(), word.declension.clone());
+        let already_resolved = resolved.contains_key(&resolved_key);
+
         let parsed;
         let mut declension = word.declension.clone();
-        if let Some(already) = find_in_lexicon(&word.text, &word.declension).await? {
+        if let Some(res) = resolved.get(&resolved_key) {
+            debug!("{} already resolved in this run", word.text);
+            parsed = res.entry.clone();
+            declension = res.declension.clone();
+        } else if let Some(already) = find_in_lexicon(&word.text, &word.declension).await? {
             debug!("{} already in lexicon", word.text);
             parsed = already;
         } else {
@@ -154,14 +260,34 @@ pub async fn import() -> Result<(), SafeError> {
             }
         }
 
+        resolved.insert(
+            resolved_key,
+            ResolvedWord {
+                entry: parsed.clone(),
+                declension: declension.clone(),
+            },
+        );
+
+        word.lemma = Some(parsed.lemma.clone());
+        if verse.words[word_i].lemma != word.lemma {
+            verse.words[word_i].lemma = word.lemma.clone();
+            VerseRepo::update_one(verse).await?;
+        }
+
         let is_indeclinable = matches!(
             word.declension.decl_type,
             Some(DeclensionType::Indeclinable)
         );
 
-        if parsed.inflections.first().is_some() && !is_indeclinable {
+        if !parsed.inflections.is_empty() && !is_indeclinable {
             let parsed_inflection = parsed.inflections.first().unwrap();
-            let inflecteds = parsed_inflection.find_inflection(&declension);
+            let inflecteds = match parsed_inflection.find_inflection(&declension) {
+                Ok(inflecteds) => inflecteds,
+                Err(e) => {
+                    warn!("could not look up inflections of {}: {}", word.text, e);
+                    vec![]
+                }
+            };
             let inflecteds = inflecteds
                 .iter()
                 .map(|x| x.clone().into())
@@ -174,21 +300,30 @@ pub async fn import() -> Result<(), SafeError> {
                 if inflected != word.text {
                     debug!("{} changing to {}", word.text, inflected);
                     word.text = inflected.to_string();
-                    update_word(&mut verse, word, word_i).await?;
                 } else {
                     debug!("{} already inflected", word.text);
                 }
             } else {
                 debug!(
-                    "{} not found with inflection {:?} in {:?}",
+                    "{} not found with inflection {} in {:?}",
                     word.text, word.declension, parsed.inflections
                 );
             }
         } else if parsed.lemma != word.text {
             if matches!(word.declension.part_of_speech, PartOfSpeech::Verb) {
+                if mode == ImportMode::Queue {
+                    let review = PendingReview::new(
+                        verse,
+                        word_i,
+                        &parsed.lemma,
+                        ReviewReason::VerbNotInflected,
+                    );
+                    ReviewService::submit(&review).await?;
+                    continue;
+                }
                 let skip = cliclack::confirm(format!(
-                    "could not inflect verb {} to lemma {}. skip?",
-                    word.text, parsed.lemma
+                    "could not inflect verb {} ({:#}) to lemma {}. skip?",
+                    word.text, word.declension, parsed.lemma
                 ))
                 .initial_value(false)
                 .interact()?;
@@ -205,17 +340,15 @@ pub async fn import() -> Result<(), SafeError> {
                 word.text, parsed.lemma
             );
             word.text = parsed.lemma.to_owned();
-            update_word(&mut verse, word, word_i).await?;
         }
 
-        if word.declension != declension {
-            word.declension = declension.clone();
-            update_word(&mut verse, word, word_i).await?;
-        }
+        word.declension = declension.clone();
+        update_word(verse, word, word_i, mode).await?;
 
-        if find_in_lexicon(&word.text, &word.declension)
-            .await?
-            .is_none()
+        if !already_resolved
+            && find_in_lexicon(&word.text, &word.declension)
+                .await?
+                .is_none()
         {
             LexiconRepo::insert_one(parsed.clone()).await?;
 
@@ -163,7 +163,7 @@ fn build_query_urls(word: Cow<str>, decl: &Declension) -> Vec<Cow<str>> {
         _ => panic!("unsupported part of speech: {:?}", pos),
     };
 
-    return categories.iter().map(|x| Cow::<str>::from(format!("https://en.wiktionary.org/w/api.php?format=json&action=query&list=search&srsearch={word}+incategory:{x}"))).collect();
+    categories.iter().map(|x| Cow::<str>::from(format!("https://en.wiktionary.org/w/api.php?format=json&action=query&list=search&srsearch={word}+incategory:{x}"))).collect()
 }
 
 #[derive(Debug, Deserialize)]
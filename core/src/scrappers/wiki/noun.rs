@@ -95,7 +95,7 @@ fn extract_declension_type(doc: &Html) -> Result<DeclensionType, SafeError> {
     .unwrap()
     .text()
     .collect::<String>();
-    let decl_str = headword.split(';').last().unwrap().to_lowercase();
+    let decl_str = headword.split(';').next_back().unwrap().to_lowercase();
     let decl_str = decl_str.trim();
 
     let decl_type = match decl_str {
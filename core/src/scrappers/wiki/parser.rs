@@ -89,9 +89,14 @@ pub async fn parse_word(
         );
     }
 
+    for inflection in &mut inflections {
+        inflection.part_of_speech = Some(declension.part_of_speech);
+    }
+
     Ok(ParseWordResult {
         entry: LexiconEntry {
             lemma: lemma.into(),
+            lemma_key: None,
             inflections,
             definitions,
         },
@@ -0,0 +1,60 @@
+use mongodb::{bson::doc, options::ReplaceOptions, Collection};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    api::verse::verse_model::VerseRef,
+    error::{MapErrSafe, SafeError},
+    persistence::get_db,
+};
+
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportProgress {
+    #[serde(rename = "_id")]
+    pub key: String,
+    pub last_verse: Option<VerseRef>,
+    pub completed: bool,
+}
+
+impl ImportProgress {
+    pub fn new(key: String) -> Self {
+        Self {
+            key,
+            last_verse: None,
+            completed: false,
+        }
+    }
+}
+
+pub struct ImportProgressRepo;
+
+impl ImportProgressRepo {
+    pub const COLLECTION_NAME: &'static str = "import_jobs";
+
+    pub async fn find_one(key: &str) -> Result<Option<ImportProgress>, SafeError> {
+        get_collection()
+            .await?
+            .find_one(doc! {"_id": key}, None)
+            .await
+            .map_err_safe()
+    }
+
+    pub async fn update_one(progress: &ImportProgress) -> Result<(), SafeError> {
+        let options = ReplaceOptions::builder().upsert(true).build();
+
+        get_collection()
+            .await?
+            .replace_one(doc! {"_id": &progress.key}, progress, options)
+            .await
+            .map_err_safe()?;
+
+        Ok(())
+    }
+}
+
+async fn get_collection() -> Result<Collection<ImportProgress>, SafeError> {
+    Ok(get_db()
+        .await?
+        .collection::<ImportProgress>(ImportProgressRepo::COLLECTION_NAME))
+}
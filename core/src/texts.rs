@@ -1,7 +1,22 @@
 use serde::{Deserialize, Serialize};
-use strum::{Display};
+use strum::{Display, EnumIter, EnumString};
+use utoipa::ToSchema;
 
-#[derive(Debug, PartialEq, Clone, Copy, Deserialize, Display, Serialize)]
+#[derive(
+    Debug,
+    PartialEq,
+    Clone,
+    Copy,
+    Deserialize,
+    Display,
+    Serialize,
+    Hash,
+    Eq,
+    PartialOrd,
+    Ord,
+    EnumString,
+    ToSchema,
+)]
 #[strum(serialize_all = "snake_case")]
 #[serde(rename_all = "snake_case")]
 #[allow(dead_code)]
@@ -9,7 +24,22 @@ pub enum Collection {
     NewTestament,
 }
 
-#[derive(Debug, Display, PartialEq, Clone, Copy, Deserialize, Serialize)]
+#[derive(
+    Debug,
+    Display,
+    PartialEq,
+    Clone,
+    Copy,
+    Deserialize,
+    Serialize,
+    Hash,
+    Eq,
+    PartialOrd,
+    Ord,
+    EnumIter,
+    EnumString,
+    ToSchema,
+)]
 #[strum(serialize_all = "snake_case")]
 #[serde(rename_all = "snake_case")]
 #[allow(dead_code)]
@@ -42,3 +72,37 @@ pub enum Book {
     Jude,
     Revelation,
 }
+
+impl Book {
+    pub fn chapter_count(&self) -> u8 {
+        match self {
+            Book::Matthew => 28,
+            Book::Mark => 16,
+            Book::Luke => 24,
+            Book::John => 21,
+            Book::Acts => 28,
+            Book::Romans => 16,
+            Book::Corinthians1 => 16,
+            Book::Corinthians2 => 13,
+            Book::Galatians => 6,
+            Book::Ephesians => 6,
+            Book::Philippians => 4,
+            Book::Colossians => 4,
+            Book::Thessalonians1 => 5,
+            Book::Thessalonians2 => 3,
+            Book::Timothy1 => 6,
+            Book::Timothy2 => 4,
+            Book::Titus => 3,
+            Book::Philemon => 1,
+            Book::Hebrews => 13,
+            Book::James => 5,
+            Book::Peter1 => 5,
+            Book::Peter2 => 3,
+            Book::John1 => 5,
+            Book::John2 => 1,
+            Book::John3 => 1,
+            Book::Jude => 1,
+            Book::Revelation => 22,
+        }
+    }
+}
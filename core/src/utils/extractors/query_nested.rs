@@ -7,6 +7,8 @@ use actix_web::{dev::Payload, FromRequest, HttpRequest};
 use serde::de::DeserializeOwned;
 use tracing::debug;
 
+use crate::api::error::ApiError;
+
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub struct QueryNested<T>(pub T);
 
@@ -45,7 +47,7 @@ impl<T: DeserializeOwned> FromRequest for QueryNested<T> {
                         req.path()
                     );
 
-                    actix_web::error::ErrorBadRequest(e)
+                    ApiError::bad_request(e.to_string()).into()
                 }),
         )
     }
@@ -3,5 +3,7 @@ pub mod capitalize;
 pub mod closest;
 pub mod decode_html;
 pub mod remove_diacritics;
+pub mod search_key;
 pub mod skip_last;
 pub mod snake_case;
+pub mod syllables;
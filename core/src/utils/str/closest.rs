@@ -24,18 +24,18 @@ pub fn closest_with_score(s: Cow<str>, list: &[Cow<str>]) -> Vec<Scored> {
 
     scores.sort_by(|b, a| a.1.partial_cmp(&b.1).unwrap_or(Ordering::Equal));
 
-    return scores
+    scores
         .iter()
         .map(|x| Scored {
             value: x.0.clone(),
             score: x.1,
         })
-        .collect();
+        .collect()
 }
 
 pub fn closest(s: Cow<str>, list: &[Cow<str>]) -> Vec<Cow<str>> {
-    return closest_with_score(s, list)
+    closest_with_score(s, list)
         .iter()
         .map(|x| x.value.clone())
-        .collect();
+        .collect()
 }
@@ -4,6 +4,6 @@ pub trait DecodeHtml {
 
 impl<S: ?Sized + AsRef<str>> DecodeHtml for S {
     fn decode_html(&self) -> String {
-        return html_escape::decode_html_entities(self).into();
+        html_escape::decode_html_entities(self).into()
     }
 }
@@ -1,271 +1,117 @@
-pub fn remove_diacritics_char(c: char) -> char {
+use unicode_normalization::{
+    char::{decompose_canonical, is_combining_mark},
+    UnicodeNormalization,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Diacritic {
+    Acute,
+    Grave,
+    Circumflex,
+    SmoothBreathing,
+    RoughBreathing,
+    IotaSubscript,
+    Diaeresis,
+    Macron,
+    Breve,
+}
+
+impl Diacritic {
+    pub fn from_mark(c: char) -> Option<Diacritic> {
+        match c {
+            '\u{0301}' | '\u{0341}' => Some(Diacritic::Acute),
+            '\u{0300}' | '\u{0340}' => Some(Diacritic::Grave),
+            '\u{0342}' | '\u{0302}' | '\u{0303}' => Some(Diacritic::Circumflex),
+            '\u{0313}' | '\u{0343}' => Some(Diacritic::SmoothBreathing),
+            '\u{0314}' => Some(Diacritic::RoughBreathing),
+            '\u{0345}' => Some(Diacritic::IotaSubscript),
+            '\u{0308}' => Some(Diacritic::Diaeresis),
+            '\u{0304}' => Some(Diacritic::Macron),
+            '\u{0306}' => Some(Diacritic::Breve),
+            _ => None,
+        }
+    }
+}
+
+fn fold_letter(c: char) -> char {
     match c {
-        'á' => 'a',
-        'à' => 'a',
-        'ä' => 'a',
-        'â' => 'a',
-        'é' => 'e',
-        'è' => 'e',
-        'ë' => 'e',
-        'ê' => 'e',
-        'í' => 'i',
-        'ì' => 'i',
-        'ï' => 'i',
-        'î' => 'i',
-        'ó' => 'o',
-        'ò' => 'o',
-        'ö' => 'o',
-        'ô' => 'o',
-        'ú' => 'u',
-        'ù' => 'u',
-        'ü' => 'u',
-        'û' => 'u',
-        'ý' => 'y',
-        'ÿ' => 'y',
-        'ñ' => 'n',
-        'ç' => 'c',
         'ß' => 's',
         'æ' => 'a',
         'œ' => 'o',
         'ø' => 'o',
-        'å' => 'a',
-        'Å' => 'A',
         'Æ' => 'A',
         'Œ' => 'O',
         'Ø' => 'O',
-        'Á' => 'A',
-        'À' => 'A',
-        'Ä' => 'A',
-        'Â' => 'A',
-        'É' => 'E',
-        'È' => 'E',
-        'Ë' => 'E',
-        'Ê' => 'E',
-        'Í' => 'I',
-        'Ì' => 'I',
-        'Ï' => 'I',
-        'Î' => 'I',
-        'Ó' => 'O',
-        'Ò' => 'O',
-        'Ö' => 'O',
-        'Ô' => 'O',
-        'Ú' => 'U',
-        'Ù' => 'U',
-        'Ü' => 'U',
-        'Û' => 'U',
-        'Ý' => 'Y',
-        'Ñ' => 'N',
-        'Ç' => 'C',
         'Þ' => 'T',
         'þ' => 't',
-        'ά' => 'α',
-        'ὰ' => 'α',
-        'ᾶ' => 'α',
-        'ἀ' => 'α',
-        'ἄ' => 'α',
-        'ἂ' => 'α',
-        'ἆ' => 'α',
-        'ἁ' => 'α',
-        'ἅ' => 'α',
-        'ἃ' => 'α',
-        'ἇ' => 'α',
-        'ᾱ' => 'α',
-        'ᾰ' => 'α',
-        'ᾳ' => 'α',
-        'ᾴ' => 'α',
-        'ᾲ' => 'α',
-        'ᾷ' => 'α',
-        'ᾁ' => 'α',
-        'ᾅ' => 'α',
-        'ᾃ' => 'α',
-        'ᾇ' => 'α',
-        'έ' => 'ε',
-        'ὲ' => 'ε',
-        'ἐ' => 'ε',
-        'ἔ' => 'ε',
-        'ἒ' => 'ε',
-        'ἑ' => 'ε',
-        'ἕ' => 'ε',
-        'ἓ' => 'ε',
-        'ή' => 'η',
-        'ὴ' => 'η',
-        'ῆ' => 'η',
-        'ἠ' => 'η',
-        'ἤ' => 'η',
-        'ἢ' => 'η',
-        'ἦ' => 'η',
-        'ἡ' => 'η',
-        'ἥ' => 'η',
-        'ἣ' => 'η',
-        'ἧ' => 'η',
-        'ι' => 'ι',
-        'ῖ' => 'ι',
-        'ἰ' => 'ι',
-        'ἴ' => 'ι',
-        'ἲ' => 'ι',
-        'ἶ' => 'ι',
-        'ἱ' => 'ι',
-        'ἵ' => 'ι',
-        'ἳ' => 'ι',
-        'ἷ' => 'ι',
-        'ί' => 'ι',
-        'ὶ' => 'ι',
-        'ῑ' => 'ι',
-        'ῐ' => 'ι',
-        'ΐ' => 'ι',
-        'ῒ' => 'ι',
-        'ῗ' => 'ι',
-        '῁' => 'ι',
-        'ϊ' => 'ι',
-        'ΐ' => 'ι',
-        'ὀ' => 'ο',
-        'ὄ' => 'ο',
-        'ὂ' => 'ο',
-        '὆' => 'ο',
-        'ὁ' => 'ο',
-        'ὅ' => 'ο',
-        'ὃ' => 'ο',
-        '὇' => 'ο',
-        'ό' => 'ο',
-        'ὸ' => 'ο',
-        'ῤ' => 'ρ',
-        'ῥ' => 'ρ',
-        'ῦ' => 'υ',
-        'ὐ' => 'υ',
-        'ὔ' => 'υ',
-        'ὒ' => 'υ',
-        'ὖ' => 'υ',
-        'ὑ' => 'υ',
-        'ὕ' => 'υ',
-        'ὓ' => 'υ',
-        'ὗ' => 'υ',
-        'ύ' => 'υ',
-        'ὺ' => 'υ',
-        'ῠ' => 'υ',
-        'ῡ' => 'υ',
-        'ῢ' => 'υ',
-        'ΰ' => 'υ',
-        'ῧ' => 'υ',
-        'ϋ' => 'υ',
-        'ΰ' => 'υ',
-        'ὠ' => 'ω',
-        'ὤ' => 'ω',
-        'ὢ' => 'ω',
-        'ὦ' => 'ω',
-        'ὡ' => 'ω',
-        'ὥ' => 'ω',
-        'ὣ' => 'ω',
-        'ὧ' => 'ω',
-        'ώ' => 'ω',
-        'ὼ' => 'ω',
-        'ῳ' => 'ω',
-        'ῴ' => 'ω',
-        'ῲ' => 'ω',
-        'ῷ' => 'ω',
-        'ᾧ' => 'ω',
-        'ᾣ' => 'ω',
-        'ᾢ' => 'ω',
-        'ᾥ' => 'ω',
-        'ᾡ' => 'ω',
-        'ᾤ' => 'ω',
-        'ᾠ' => 'ω',
-        'ᾦ' => 'ω',
-        'ῶ' => 'ω',
-        'Ά' => 'Α',
-        'Ὰ' => 'Α',
-        'Ἀ' => 'Α',
-        'Ἄ' => 'Α',
-        'Ἂ' => 'Α',
-        'Ἆ' => 'Α',
-        'Ἁ' => 'Α',
-        'Ἅ' => 'Α',
-        'Ἃ' => 'Α',
-        'Ἇ' => 'Α',
-        'Ᾱ' => 'Α',
-        'Ᾰ' => 'Α',
-        'ᾼ' => 'Α',
-        'ᾈ' => 'Α',
-        'ᾌ' => 'Α',
-        'ᾊ' => 'Α',
-        'ᾎ' => 'Α',
-        'ᾉ' => 'Α',
-        'ᾍ' => 'Α',
-        'ᾋ' => 'Α',
-        'ᾏ' => 'Α',
-        'Έ' => 'Ε',
-        'Ὲ' => 'Ε',
-        'Ἐ' => 'Ε',
-        'Ἔ' => 'Ε',
-        'Ἒ' => 'Ε',
-        'Ἢ' => 'Ε',
-        'Ἤ' => 'Ε',
-        'Ἠ' => 'Ε',
-        'Ἦ' => 'Ε',
-        'Ἡ' => 'Ε',
-        'Ἥ' => 'Ε',
-        'Ἣ' => 'Ε',
-        'Ἧ' => 'Ε',
-        'Ή' => 'Η',
-        'Ὴ' => 'Η',
-        'ῌ' => 'Η',
-        'ᾘ' => 'Η',
-        'ᾜ' => 'Η',
-        'ᾚ' => 'Η',
-        'ᾞ' => 'Η',
-        'ᾙ' => 'Η',
-        'ᾝ' => 'Η',
-        'ᾛ' => 'Η',
-        'ᾟ' => 'Η',
-        'Ί' => 'Ι',
-        'Ὶ' => 'Ι',
-        'Ἰ' => 'Ι',
-        'Ἴ' => 'Ι',
-        'Ἲ' => 'Ι',
-        'Ἶ' => 'Ι',
-        'Ἱ' => 'Ι',
-        'Ἵ' => 'Ι',
-        'Ἳ' => 'Ι',
-        'Ἷ' => 'Ι',
-        'Ό' => 'Ο',
-        'Ὸ' => 'Ο',
-        'Ὀ' => 'Ο',
-        'Ὄ' => 'Ο',
-        'Ὂ' => 'Ο',
-        'Ὅ' => 'Ο',
-        'Ὁ' => 'Ο',
-        'Ὃ' => 'Ο',
-        'Ώ' => 'Ω',
-        'Ὼ' => 'Ω',
-        'Ὠ' => 'Ω',
-        'Ὤ' => 'Ω',
-        'Ὢ' => 'Ω',
-        'Ὦ' => 'Ω',
-        'Ὡ' => 'Ω',
-        'Ὥ' => 'Ω',
-        'Ὣ' => 'Ω',
-        'Ὧ' => 'Ω',
-        'ῼ' => 'Ω',
-        'ᾨ' => 'Ω',
-        'ᾬ' => 'Ω',
-        'ᾪ' => 'Ω',
-        'ᾮ' => 'Ω',
-        'ᾩ' => 'Ω',
-        'ᾭ' => 'Ω',
-        'ᾫ' => 'Ω',
-        'ᾯ' => 'Ω',
-        'Ῥ' => 'Ρ',
-        'Ὑ' => 'Υ',
-        'Ὕ' => 'Υ',
-        'Ὓ' => 'Υ',
-        'Ὗ' => 'Υ',
-        'Ὺ' => 'Υ',
-        'Ῠ' => 'Υ',
-        'Ῡ' => 'Υ',
-        'Ύ' => 'Υ',
         _ => c,
     }
 }
 
+pub fn remove_diacritics_char(c: char) -> char {
+    let mut base = None;
+    decompose_canonical(c, |x| {
+        if base.is_none() && !is_combining_mark(x) {
+            base = Some(x);
+        }
+    });
+
+    fold_letter(base.unwrap_or(c))
+}
+
+pub fn remove_diacritics_with(s: &str, keep: &[Diacritic]) -> String {
+    s.nfd()
+        .filter(|c| {
+            !is_combining_mark(*c) || Diacritic::from_mark(*c).is_some_and(|d| keep.contains(&d))
+        })
+        .map(fold_letter)
+        .nfc()
+        .collect()
+}
+
 pub fn remove_diacritics(s: &str) -> String {
-    s.chars().map(remove_diacritics_char).collect()
+    remove_diacritics_with(s, &[])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strips_polytonic_diacritics() {
+        assert_eq!(remove_diacritics("ᾧ ῥῆμα ΐ ᾄδω"), "ω ρημα ι αδω");
+        assert_eq!(remove_diacritics("Ἄνθρωπος"), "Ανθρωπος");
+        for (c, expected) in [('ᾧ', 'ω'), ('ῥ', 'ρ'), ('ΐ', 'ι'), ('ᾄ', 'α'), ('Ἄ', 'Α')]
+        {
+            assert_eq!(remove_diacritics_char(c), expected);
+        }
+    }
+
+    #[test]
+    fn keeps_the_diacritics_asked_for() {
+        let keep = |s, diacritic| remove_diacritics_with(s, &[diacritic]);
+        assert_eq!(keep("ἄνθρωπος", Diacritic::Acute), "άνθρωπος");
+        assert_eq!(keep("καλὸς", Diacritic::Grave), "καλὸς");
+        assert_eq!(keep("ὧν", Diacritic::Circumflex), "ῶν");
+        assert_eq!(keep("ἄνθρωπος", Diacritic::SmoothBreathing), "ἀνθρωπος");
+        assert_eq!(keep("ὁδός", Diacritic::RoughBreathing), "ὁδος");
+        assert_eq!(keep("ᾧ", Diacritic::IotaSubscript), "ῳ");
+        assert_eq!(keep("ΐ", Diacritic::Diaeresis), "ϊ");
+        assert_eq!(keep("ᾱ\u{0301}", Diacritic::Macron), "ᾱ");
+        assert_eq!(keep("ᾰ\u{0301}", Diacritic::Breve), "ᾰ");
+
+        assert_eq!(
+            remove_diacritics_with("ᾧ", &[Diacritic::RoughBreathing, Diacritic::IotaSubscript]),
+            "ᾡ"
+        );
+    }
+
+    #[test]
+    fn folds_latin_ligatures() {
+        assert_eq!(
+            remove_diacritics("Œuvre Ærø Þór straße"),
+            "Ouvre Aro Tor strase"
+        );
+        assert_eq!(remove_diacritics("café naïve"), "cafe naive");
+    }
 }
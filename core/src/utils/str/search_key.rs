@@ -0,0 +1,9 @@
+use super::remove_diacritics::remove_diacritics;
+
+pub fn search_key(s: &str) -> String {
+    remove_diacritics(s.trim())
+        .chars()
+        .flat_map(char::to_lowercase)
+        .map(|c| if c == 'ς' { 'σ' } else { c })
+        .collect()
+}
@@ -0,0 +1,253 @@
+use serde::Serialize;
+use unicode_normalization::{char::decompose_canonical, UnicodeNormalization};
+use utoipa::ToSchema;
+
+use crate::utils::str::remove_diacritics::{remove_diacritics_char, Diacritic};
+
+/// Length of a vowel or syllable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Quantity {
+    Long,
+    Short,
+    /// An α, ι or υ written without a macron, breve or anything else telling its length.
+    Ambiguous,
+}
+
+/// The last three syllables of a word, the only ones an accent can fall on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Position {
+    Ultima,
+    Penult,
+    Antepenult,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Syllable {
+    pub text: String,
+    /// The vowel or diphthong of the syllable, without diacritics.
+    pub nucleus: String,
+    /// Index, in chars of `text`, of the vowel that carries an accent: the second one of a
+    /// diphthong.
+    pub accent_index: usize,
+    pub quantity: Quantity,
+}
+
+const DIPHTHONGS: [&str; 9] = ["αι", "ει", "οι", "υι", "αυ", "ευ", "ου", "ηυ", "ωυ"];
+
+fn is_vowel(c: char) -> bool {
+    matches!(c, 'α' | 'ε' | 'η' | 'ι' | 'ο' | 'υ' | 'ω')
+}
+
+/// Length of a single vowel as written: η and ω are long, ε and ο short, and α, ι and υ
+/// ambiguous unless a macron, breve, circumflex or iota subscript tells. `None` if `c` isn't a
+/// vowel.
+pub fn vowel_quantity(c: char) -> Option<Quantity> {
+    let base = remove_diacritics_char(c).to_lowercase().next()?;
+    if !is_vowel(base) {
+        return None;
+    }
+    Some(quantity(&base.to_string(), &diacritics(c)))
+}
+
+fn quantity(nucleus: &str, marks: &[Diacritic]) -> Quantity {
+    let long_mark = marks.iter().any(|x| {
+        matches!(
+            x,
+            Diacritic::Circumflex | Diacritic::IotaSubscript | Diacritic::Macron
+        )
+    });
+
+    if nucleus.chars().count() > 1 || matches!(nucleus, "η" | "ω") || long_mark {
+        Quantity::Long
+    } else if matches!(nucleus, "ε" | "ο") || marks.contains(&Diacritic::Breve) {
+        Quantity::Short
+    } else {
+        Quantity::Ambiguous
+    }
+}
+
+fn diacritics(c: char) -> Vec<Diacritic> {
+    let mut marks = Vec::new();
+    decompose_canonical(c, |x| marks.extend(Diacritic::from_mark(x)));
+    marks
+}
+
+/// Whether consonants can start a syllable together, as they can a word: a stop and a liquid
+/// or nasal (κλ, τρ, πν), σ and a stop (στ, σκ), or some of their combinations (στρ, πτ, μν).
+fn is_onset(consonants: &[char]) -> bool {
+    let is_stop = |x: char| matches!(x, 'π' | 'β' | 'φ' | 'τ' | 'δ' | 'θ' | 'κ' | 'γ' | 'χ');
+    let is_liquid = |x: char| matches!(x, 'λ' | 'ρ' | 'μ' | 'ν');
+
+    match consonants {
+        [_] => true,
+        [a, b] if is_stop(*a) && is_liquid(*b) => true,
+        ['σ', b] if is_stop(*b) => true,
+        ['π' | 'κ', 'τ'] | ['φ' | 'χ', 'θ'] | ['μ', 'ν'] => true,
+        ['σ', b, c] if is_stop(*b) && is_liquid(*c) => true,
+        _ => false,
+    }
+}
+
+/// Splits `word` into syllables, one per vowel or diphthong. Consonants between two vowels go
+/// with the second one as far as they can start a word together (λό-γος, ἄ-στρον, ἄλ-λος). A
+/// diaeresis or a mark on its first vowel splits a diphthong (ἀ-ΐ-διος, ἄ-υ-πνος), and an iota
+/// subscript stays with its vowel (τι-μῇ).
+pub fn syllables(word: &str) -> Vec<Syllable> {
+    let chars = word.nfc().collect::<Vec<_>>();
+    let bases = chars
+        .iter()
+        .map(|x| {
+            remove_diacritics_char(*x)
+                .to_lowercase()
+                .next()
+                .unwrap_or(*x)
+        })
+        .collect::<Vec<_>>();
+
+    // Char ranges of each vowel or diphthong.
+    let mut nuclei = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        if !is_vowel(bases[i]) {
+            i += 1;
+            continue;
+        }
+
+        let pair = bases[i..].iter().take(2).collect::<String>();
+        let first_marked = diacritics(chars[i])
+            .iter()
+            .any(|x| !matches!(x, Diacritic::Macron | Diacritic::Breve));
+        let is_diphthong = DIPHTHONGS.contains(&pair.as_str())
+            && !first_marked
+            && !diacritics(chars[i + 1]).contains(&Diacritic::Diaeresis);
+
+        let len = if is_diphthong { 2 } else { 1 };
+        nuclei.push((i, i + len));
+        i += len;
+    }
+
+    let mut syllables = Vec::new();
+    let mut start = 0;
+    for (n, (nucleus_start, nucleus_end)) in nuclei.iter().copied().enumerate() {
+        let end = match nuclei.get(n + 1) {
+            None => chars.len(),
+            Some((next, _)) => {
+                let consonants = &bases[nucleus_end..*next];
+                let onset = (1..=consonants.len())
+                    .rev()
+                    .find(|x| is_onset(&consonants[consonants.len() - x..]))
+                    .unwrap_or(0);
+                next - onset
+            }
+        };
+
+        let marks = chars[nucleus_start..nucleus_end]
+            .iter()
+            .flat_map(|x| diacritics(*x))
+            .collect::<Vec<_>>();
+        let nucleus = bases[nucleus_start..nucleus_end].iter().collect::<String>();
+        let quantity = quantity(&nucleus, &marks);
+
+        syllables.push(Syllable {
+            text: chars[start..end].iter().collect(),
+            nucleus,
+            accent_index: nucleus_end - 1 - start,
+            quantity,
+        });
+        start = end;
+    }
+
+    syllables
+}
+
+/// Access to the syllables an accent can fall on, counted from the end of the word.
+pub trait Syllables {
+    fn ultima(&self) -> Option<&Syllable>;
+    fn penult(&self) -> Option<&Syllable>;
+    fn antepenult(&self) -> Option<&Syllable>;
+    /// Position of the syllable at `index` from the start, if it is one of the last three.
+    fn position(&self, index: usize) -> Option<Position>;
+}
+
+impl Syllables for [Syllable] {
+    fn ultima(&self) -> Option<&Syllable> {
+        self.iter().nth_back(0)
+    }
+
+    fn penult(&self) -> Option<&Syllable> {
+        self.iter().nth_back(1)
+    }
+
+    fn antepenult(&self) -> Option<&Syllable> {
+        self.iter().nth_back(2)
+    }
+
+    fn position(&self, index: usize) -> Option<Position> {
+        match self.len().checked_sub(index + 1)? {
+            0 => Some(Position::Ultima),
+            1 => Some(Position::Penult),
+            2 => Some(Position::Antepenult),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn split(word: &str) -> Vec<String> {
+        syllables(word).into_iter().map(|x| x.text).collect()
+    }
+
+    #[test]
+    fn splits_between_consonants() {
+        assert_eq!(split("λόγος"), ["λό", "γος"]);
+        assert_eq!(split("ἄστρον"), ["ἄ", "στρον"]);
+        assert_eq!(split("ἄλλος"), ["ἄλ", "λος"]);
+        assert_eq!(split("ἄνθρωπος"), ["ἄν", "θρω", "πος"]);
+        assert_eq!(split("φύλαξ"), ["φύ", "λαξ"]);
+    }
+
+    #[test]
+    fn keeps_diphthongs_together() {
+        assert_eq!(split("παιδεύω"), ["παι", "δεύ", "ω"]);
+        assert_eq!(split("οὐρανοῦ"), ["οὐ", "ρα", "νοῦ"]);
+        assert_eq!(split("ἀΐδιος"), ["ἀ", "ΐ", "δι", "ος"]);
+        assert_eq!(split("ἄυπνος"), ["ἄ", "υ", "πνος"]);
+        assert_eq!(split("τιμῇ"), ["τι", "μῇ"]);
+    }
+
+    #[test]
+    fn tells_vowel_length() {
+        let quantities = |word| {
+            syllables(word)
+                .into_iter()
+                .map(|x| x.quantity)
+                .collect::<Vec<_>>()
+        };
+        use Quantity::*;
+
+        assert_eq!(quantities("λόγος"), [Short, Short]);
+        assert_eq!(quantities("χώρα"), [Long, Ambiguous]);
+        assert_eq!(quantities("χώρᾱ"), [Long, Long]);
+        assert_eq!(quantities("θάλαττᾰ"), [Ambiguous, Ambiguous, Short]);
+        assert_eq!(quantities("γλῶσσα"), [Long, Ambiguous]);
+        assert_eq!(quantities("τιμῇ"), [Ambiguous, Long]);
+        assert_eq!(vowel_quantity('ῦ'), Some(Long));
+        assert_eq!(vowel_quantity('λ'), None);
+    }
+
+    #[test]
+    fn finds_the_syllables_an_accent_can_fall_on() {
+        let syllables = syllables("ἄνθρωπος");
+        assert_eq!(syllables.ultima().map(|x| x.text.as_str()), Some("πος"));
+        assert_eq!(syllables.penult().map(|x| x.text.as_str()), Some("θρω"));
+        assert_eq!(syllables.position(0), Some(Position::Antepenult));
+        assert_eq!(syllables.position(2), Some(Position::Ultima));
+        assert_eq!(syllables.position(3), None);
+        assert_eq!(syllables[2].accent_index, 1);
+    }
+}
@@ -0,0 +1,411 @@
+use std::{collections::BTreeMap, path::Path};
+
+use serde::Serialize;
+use serde_json::Value;
+use tracing::info;
+use unicode_normalization::UnicodeNormalization;
+
+use crate::{
+    api::lexicon::{
+        lexicon_model::{
+            LexiconEntry, LexiconFilter, NounInflectionGenders, VerbInflectionTenses,
+            VerbInflectionThemes, WordInflection,
+        },
+        lexicon_repo::LexiconRepo,
+    },
+    error::SafeError,
+    export::write_json,
+    grammar::{Declension, DeclensionType, Dialect, Gender, Noun, PartOfSpeech, Voice},
+    infl::{
+        noun,
+        verb::{self, PrincipalParts},
+    },
+};
+
+#[derive(Debug, Serialize)]
+struct Report {
+    lemmas: usize,
+    cells: usize,
+    mismatches: Vec<Mismatch>,
+    /// Stored inflections the generators could not regenerate or compare.
+    errors: Vec<GenerationError>,
+}
+
+/// A cell of a stored table whose forms the generators don't give.
+#[derive(Debug, Serialize)]
+struct Mismatch {
+    lemma: String,
+    /// Path of the cell in the inflection, e.g. `verb.aorist.thematic.contracted.indicative.
+    /// active.singular.first`.
+    path: String,
+    dialects: Vec<Dialect>,
+    stored: Vec<String>,
+    generated: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct GenerationError {
+    lemma: String,
+    dialects: Vec<Dialect>,
+    error: String,
+}
+
+/// Regenerates the noun and verb paradigms of the lexicon with the `infl` generators, from the
+/// lemma and the principal parts found in the stored tables, and writes to `path` each cell of
+/// the stored tables where they disagree.
+pub async fn validate_paradigms(path: &Path) -> Result<(), SafeError> {
+    let entries = LexiconRepo::find_many(LexiconFilter::default()).await?;
+    let mut report = Report {
+        lemmas: 0,
+        cells: 0,
+        mismatches: Vec::new(),
+        errors: Vec::new(),
+    };
+
+    for entry in &entries {
+        let mut compared = false;
+        for inflection in &entry.inflections {
+            let Some(generated) = regenerate(&entry.lemma, inflection) else {
+                continue;
+            };
+
+            let result = generated.and_then(|generated| {
+                compared = true;
+                compare(entry, inflection, &generated, &mut report)
+            });
+            if let Err(error) = result {
+                report.errors.push(GenerationError {
+                    lemma: entry.lemma.clone(),
+                    dialects: inflection.dialects.clone(),
+                    error: error.to_string(),
+                });
+            }
+        }
+        if compared {
+            report.lemmas += 1;
+        }
+    }
+
+    report
+        .mismatches
+        .sort_by(|a, b| (&a.lemma, &a.path, &a.dialects).cmp(&(&b.lemma, &b.path, &b.dialects)));
+    write_json(path, &report)?;
+
+    info!(
+        "{} mismatches in {} cells of {} lemmas, {} inflections not generated, report written to {}",
+        report.mismatches.len(),
+        report.cells,
+        report.lemmas,
+        report.errors.len(),
+        path.display()
+    );
+    Ok(())
+}
+
+/// The inflection the generators give for `stored`, if they handle its part of speech.
+fn regenerate(lemma: &str, stored: &WordInflection) -> Option<Result<WordInflection, SafeError>> {
+    let generated = WordInflection {
+        dialects: stored.dialects.clone(),
+        declension_type: stored.declension_type,
+        part_of_speech: stored.part_of_speech,
+        ..Default::default()
+    };
+
+    if let Some(genders) = &stored.noun {
+        if stored.declension_type == Some(DeclensionType::Indeclinable) {
+            return None;
+        }
+        return Some(
+            regenerate_noun(lemma, stored.declension_type, genders).map(|x| WordInflection {
+                noun: Some(Box::new(x)),
+                ..generated
+            }),
+        );
+    }
+    if let Some(tenses) = &stored.verb {
+        let parts = principal_parts(lemma, tenses);
+        return Some(verb::inflect(&parts).map(|x| WordInflection {
+            verb: Some(Box::new(x)),
+            ..generated
+        }));
+    }
+
+    None
+}
+
+fn regenerate_noun(
+    lemma: &str,
+    decl_type: Option<DeclensionType>,
+    genders: &NounInflectionGenders,
+) -> Result<NounInflectionGenders, SafeError> {
+    let stored = [
+        (Gender::Masculine, &genders.masculine),
+        (Gender::Feminine, &genders.feminine),
+        (Gender::Neuter, &genders.neuter),
+    ]
+    .into_iter()
+    .filter_map(|(gender, numbers)| Some((gender, numbers.as_ref()?)))
+    .collect::<Vec<_>>();
+    if stored.is_empty() {
+        return Err(format!("no gender in the table of {lemma}").into());
+    }
+
+    // Nouns of two genders (ὁ, ἡ θεός) are declined once for each.
+    let mut generated = NounInflectionGenders::default();
+    for (gender, numbers) in stored {
+        let genitive = numbers
+            .singular
+            .as_ref()
+            .and_then(|x| x.genitive.as_ref())
+            .and_then(|x| x.first())
+            .and_then(|x| x.contracted.clone());
+        let declension = Declension {
+            gender: Some(gender),
+            decl_type,
+            ..Declension::partial_default(PartOfSpeech::Noun(Noun::Common))
+        };
+
+        let inflected = noun::inflect(lemma, genitive.as_deref(), &declension)?;
+        generated = NounInflectionGenders {
+            masculine: generated.masculine.or(inflected.masculine),
+            feminine: generated.feminine.or(inflected.feminine),
+            neuter: generated.neuter.or(inflected.neuter),
+        };
+    }
+
+    Ok(generated)
+}
+
+/// The principal parts of a verb, read from the 1st person singular indicatives of its table.
+fn principal_parts(lemma: &str, tenses: &VerbInflectionTenses) -> PrincipalParts {
+    let first = |themes: &[&Option<Box<VerbInflectionThemes>>], voices: &[Voice]| {
+        voices.iter().find_map(|voice| {
+            themes
+                .iter()
+                .find_map(|themes| first_person(themes.as_deref()?, *voice))
+        })
+    };
+    let aorists = [&tenses.aorist, &tenses.aorist_2nd];
+    let perfects = [&tenses.perfect, &tenses.perfect_2nd];
+
+    PrincipalParts {
+        present: lemma.to_string(),
+        future: first(&[&tenses.future], &[Voice::Active, Voice::Middle]),
+        aorist: first(&aorists, &[Voice::Active, Voice::Middle]),
+        perfect: first(&perfects, &[Voice::Active]),
+        perfect_middle: first(&perfects, &[Voice::Middle, Voice::Passive]),
+        aorist_passive: first(&aorists, &[Voice::Passive]),
+    }
+}
+
+fn first_person(themes: &VerbInflectionThemes, voice: Voice) -> Option<String> {
+    [&themes.thematic, &themes.athematic]
+        .into_iter()
+        .find_map(|contractions| {
+            let voices = contractions
+                .as_ref()?
+                .contracted
+                .as_ref()?
+                .indicative
+                .as_ref()?;
+            let numbers = match voice {
+                Voice::Active => &voices.active,
+                Voice::Middle => &voices.middle,
+                Voice::Passive => &voices.passive,
+            };
+            let form = numbers
+                .as_ref()?
+                .singular
+                .as_ref()?
+                .first
+                .as_ref()?
+                .first()?;
+            form.contracted.clone()
+        })
+}
+
+/// Diffs each cell of the `stored` table with the same one in `generated`.
+fn compare(
+    entry: &LexiconEntry,
+    stored: &WordInflection,
+    generated: &WordInflection,
+    report: &mut Report,
+) -> Result<(), SafeError> {
+    let dialects = &stored.dialects;
+    let stored = cells(entry, stored)?;
+    let generated = cells(entry, generated)?;
+
+    for (declension, (path, stored_forms)) in &stored {
+        report.cells += 1;
+        let generated_forms = generated
+            .get(declension)
+            .map(|(_, x)| x.clone())
+            .unwrap_or_default();
+        if *stored_forms != generated_forms {
+            report.mismatches.push(Mismatch {
+                lemma: entry.lemma.clone(),
+                path: path.clone(),
+                dialects: dialects.clone(),
+                stored: stored_forms.clone(),
+                generated: generated_forms,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Normalized forms of each cell of `inflection`, keyed by the declension's JSON.
+fn cells(
+    entry: &LexiconEntry,
+    inflection: &WordInflection,
+) -> Result<BTreeMap<String, (String, Vec<String>)>, SafeError> {
+    let paradigm = LexiconEntry {
+        inflections: vec![full_forms(inflection)?],
+        ..entry.clone()
+    }
+    .paradigm(false);
+
+    let mut cells = BTreeMap::new();
+    for row in paradigm.rows {
+        let mut forms = row.forms.iter().map(|x| normalize(x)).collect::<Vec<_>>();
+        forms.sort();
+        forms.dedup();
+        cells.insert(
+            serde_json::to_string(&row.declension)?,
+            (path(&row.declension), forms),
+        );
+    }
+    Ok(cells)
+}
+
+/// Drops the stem and ending a generated form is also stored as, keeping the full forms the
+/// stored tables have.
+fn full_forms(inflection: &WordInflection) -> Result<WordInflection, SafeError> {
+    let mut value = serde_json::to_value(inflection)?;
+    drop_parts(&mut value);
+    Ok(serde_json::from_value(value)?)
+}
+
+fn drop_parts(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            // the `uncontracted` of an `InflectionForm`, not the moods of a verb's
+            if map.get("uncontracted").is_some_and(Value::is_array) {
+                map.remove("uncontracted");
+            }
+            map.values_mut().for_each(drop_parts);
+        }
+        Value::Array(values) => values.iter_mut().for_each(drop_parts),
+        _ => {}
+    }
+}
+
+fn normalize(form: &str) -> String {
+    form.trim().to_lowercase().nfc().collect()
+}
+
+/// Path of a cell, in the order the inflection nests its fields.
+fn path(declension: &Declension) -> String {
+    let mut parts = vec![declension.part_of_speech.to_string()];
+    parts.extend(declension.tense.map(|x| x.to_string()));
+    parts.extend(declension.theme.map(|x| x.to_string()));
+    parts.extend(declension.contraction.map(|x| x.to_string()));
+    parts.extend(declension.mood.map(|x| x.to_string()));
+    parts.extend(declension.voice.map(|x| x.to_string()));
+    parts.extend(declension.gender.map(|x| x.to_string()));
+    parts.extend(declension.number.map(|x| x.to_string()));
+    parts.extend(declension.person.map(|x| x.to_string()));
+    parts.extend(declension.case.map(|x| x.to_string()));
+    parts.join(".")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::lexicon::lexicon_model::InflectionForm;
+
+    fn report() -> Report {
+        Report {
+            lemmas: 0,
+            cells: 0,
+            mismatches: Vec::new(),
+            errors: Vec::new(),
+        }
+    }
+
+    fn noun(lemma: &str, genitive: &str, gender: Gender) -> NounInflectionGenders {
+        let declension = Declension {
+            gender: Some(gender),
+            ..Declension::partial_default(PartOfSpeech::Noun(Noun::Common))
+        };
+        noun::inflect(lemma, Some(genitive), &declension).unwrap()
+    }
+
+    #[test]
+    fn reads_principal_parts_back() {
+        let parts = PrincipalParts {
+            present: "λύω".to_string(),
+            future: Some("λύσω".to_string()),
+            aorist: Some("ἔλυσα".to_string()),
+            perfect: Some("λέλυκα".to_string()),
+            perfect_middle: Some("λέλυμαι".to_string()),
+            aorist_passive: Some("ἐλύθην".to_string()),
+        };
+        let tenses = verb::inflect(&parts).unwrap();
+
+        assert_eq!(principal_parts("λύω", &tenses), parts);
+    }
+
+    #[test]
+    fn regenerates_every_gender() {
+        let stored = NounInflectionGenders {
+            feminine: noun("θεός", "θεοῦ", Gender::Feminine).feminine,
+            ..noun("θεός", "θεοῦ", Gender::Masculine)
+        };
+
+        let generated = regenerate_noun("θεός", None, &stored).unwrap();
+        assert_eq!(generated, stored);
+    }
+
+    #[test]
+    fn reports_mismatched_cells() {
+        let generated = WordInflection {
+            declension_type: Some(DeclensionType::Second),
+            noun: Some(Box::new(noun("λόγος", "λόγου", Gender::Masculine))),
+            ..Default::default()
+        };
+        let mut stored = generated.clone();
+        let singular = stored
+            .noun
+            .as_mut()
+            .and_then(|x| x.masculine.as_mut())
+            .and_then(|x| x.singular.as_mut())
+            .unwrap();
+        singular.genitive = Some(vec![InflectionForm {
+            contracted: Some("λογοῦ".to_string()),
+            ..Default::default()
+        }]);
+        let entry = LexiconEntry {
+            lemma: "λόγος".to_string(),
+            lemma_key: None,
+            inflections: vec![stored.clone()],
+            definitions: vec![],
+        };
+
+        let mut report = report();
+        compare(&entry, &stored, &generated, &mut report).unwrap();
+
+        assert_eq!(report.cells, 15);
+        let [mismatch] = &report.mismatches[..] else {
+            panic!("expected one mismatch, got {:?}", report.mismatches);
+        };
+        assert_eq!(mismatch.stored, ["λογοῦ"]);
+        assert_eq!(mismatch.generated, ["λόγου"]);
+        assert!(
+            mismatch.path.ends_with("singular.genitive"),
+            "{}",
+            mismatch.path
+        );
+    }
+}